@@ -28,6 +28,15 @@ impl ToStyleStr for Rule {
             frag.write_style(&mut cond, ctx);
         }
 
+        // A rule without a block is a statement (e.g.: `@layer base, components;`).
+        if self.content.is_empty() && cond.starts_with("@layer") {
+            ctx.start(w);
+            ctx.write_padding(w);
+            w.push_str(&cond);
+            w.push_str(";\n");
+            return;
+        }
+
         let mut rule_ctx = ctx.with_rule_condition(&cond);
 
         // keyframes should always be printed as they contain a global name.
@@ -1,6 +1,7 @@
 use thiserror::Error;
 
 #[derive(Debug, Error, PartialEq)]
+#[non_exhaustive]
 pub enum Error {
     /// Failed to parse CSS.
     #[cfg_attr(documenting, doc(cfg(feature = "parser")))]
@@ -17,6 +18,31 @@ pub enum Error {
     /// This is usually raised when the style element failed to mount.
     #[error("Failed to Interact with Web API. Are you running in Browser?")]
     Web(Option<wasm_bindgen::JsValue>),
+
+    /// The container of a style manager is not connected to a document.
+    ///
+    /// This is raised when a style is mounted after the container has been detached.
+    #[error("Failed to mount style, the container is not connected to a document.")]
+    ContainerDetached,
+
+    /// An attribute of a style manager has an invalid name.
+    #[error("Failed to set attribute, due to:\n{}", .reason)]
+    Attribute { reason: String },
+
+    /// A stylesheet contains something other than frames and can not be used as keyframes.
+    #[error("Failed to create keyframes, due to:\n{}", .reason)]
+    Keyframes { reason: String },
+
+    /// A style can not be replaced in place.
+    ///
+    /// This is raised when a style of a manager in atomic mode is replaced, as its classes are
+    /// shared with other styles.
+    #[error("Failed to replace style, due to:\n{}", .reason)]
+    Replace { reason: String },
+
+    /// A compound variant refers to a variant that has not been declared.
+    #[error("Failed to create variants, due to:\n{}", .reason)]
+    Variants { reason: String },
 }
 
 impl From<std::convert::Infallible> for Error {
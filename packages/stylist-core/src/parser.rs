@@ -3,7 +3,7 @@ use std::fmt;
 
 use nom::branch::{alt, Alt};
 use nom::bytes::complete::{is_not, tag, take_while1};
-use nom::character::complete::{alpha1, alphanumeric1, anychar, char, none_of};
+use nom::character::complete::{alpha1, alphanumeric1, anychar, char, digit1, none_of};
 use nom::combinator::{fail, map, not, opt, recognize};
 use nom::error::{convert_error, ErrorKind, ParseError, VerboseError};
 use nom::multi::{many0, many1, separated_list1};
@@ -233,13 +233,20 @@ impl Parser {
     /// Parse a string interpolation.
     // TODO: Handle escaping.
     fn interpolation(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
+        let name = |i| recognize(preceded(alpha1, many0(alt((alphanumeric1, tag("_"))))))(i);
+
         traced_context(
             "Interpolation",
             Self::trimmed(expect_non_empty(delimited(
                 tag("${"),
-                Self::trimmed(recognize(preceded(
-                    alpha1,
-                    many0(alt((alphanumeric1, tag("_")))),
+                // A named argument, a positional argument (e.g.: `${0}`) or a path to a field or a
+                // method of an argument (e.g.: `${theme.primary()}`).
+                Self::trimmed(recognize(pair(
+                    alt((name, digit1)),
+                    many0(preceded(
+                        tag("."),
+                        pair(alt((name, digit1)), opt(tag("()"))),
+                    )),
                 ))),
                 tag("}"),
             ))),
@@ -339,7 +346,15 @@ impl Parser {
     /// Parses a Rule Block
     fn rule_block(i: &str, kind: RuleBlockKind) -> IResult<&str, Rule, VerboseError<&str>> {
         let cond = |i| match kind {
-            RuleBlockKind::Other => Self::at_rule_condition(i, (tag("@media"), tag("@supports"))),
+            RuleBlockKind::Other => Self::at_rule_condition(
+                i,
+                (
+                    tag("@media"),
+                    tag("@supports"),
+                    tag("@container"),
+                    tag("@layer"),
+                ),
+            ),
             RuleBlockKind::Keyframes => map(recognize(Self::condition), |m| {
                 vec![m.trim().to_string().into()]
             })(i),
@@ -452,14 +467,69 @@ impl Parser {
         )(i)
     }
 
-    /// Parse `@supports` and `@media`
+    /// Parse `@font-face`
+    fn font_face(i: &str) -> IResult<&str, Rule, VerboseError<&str>> {
+        traced_context(
+            "FontFace",
+            Self::trimmed(map(
+                preceded(
+                    terminated(tag("@font-face"), opt(Self::sp)),
+                    delimited(
+                        tag("{"),
+                        opt(|i| Self::attributes(i, false)),
+                        Self::trimmed(tag("}")),
+                    ),
+                ),
+                |p: Option<Vec<StyleAttribute>>| Rule {
+                    condition: vec!["@font-face".into()].into(),
+                    content: p
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(RuleBlockContent::StyleAttr)
+                        .collect(),
+                },
+            )),
+        )(i)
+    }
+
+    /// Parse `@layer` statements (e.g.: `@layer base, components;`)
+    fn layer_statement(i: &str) -> IResult<&str, Rule, VerboseError<&str>> {
+        traced_context(
+            "LayerStatement",
+            Self::trimmed(map(
+                terminated(preceded(tag("@layer "), is_not(";{}")), tag(";")),
+                |m: &str| Rule {
+                    condition: vec![
+                        "@layer ".into(),
+                        StringFragment {
+                            inner: m.trim().to_string().into(),
+                        },
+                    ]
+                    .into(),
+                    content: Cow::Borrowed(&[]),
+                },
+            )),
+        )(i)
+    }
+
+    /// Parse `@supports`, `@media`, `@container` and `@layer`
     fn at_rule(i: &str) -> IResult<&str, ScopeContent, VerboseError<&str>> {
         traced_context(
             "AtRule",
             Self::trimmed(expect_non_empty(map(
                 separated_pair(
                     // Collect at Rules.
-                    |i| Self::at_rule_condition(i, (tag("@supports"), tag("@media"))),
+                    |i| {
+                        Self::at_rule_condition(
+                            i,
+                            (
+                                tag("@supports"),
+                                tag("@media"),
+                                tag("@container"),
+                                tag("@layer"),
+                            ),
+                        )
+                    },
                     tag("{"),
                     // Collect contents with-in rules.
                     terminated(Parser::scope_contents, tag("}")),
@@ -495,10 +565,14 @@ impl Parser {
                 Parser::dangling_block,
                 // Or a Block
                 Parser::block,
-                // @supports and @media
+                // @layer statements
+                map(Parser::layer_statement, ScopeContent::Rule),
+                // @supports, @media, @container and @layer
                 Parser::at_rule,
                 // @keyframes
                 map(Parser::keyframes, ScopeContent::Rule),
+                // @font-face
+                map(Parser::font_face, ScopeContent::Rule),
             ))))),
         )(i)
     }
@@ -951,6 +1025,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_interpolation_paths() {
+        init();
+
+        for m in [
+            "${0}",
+            "${color}",
+            "${theme.primary}",
+            "${theme.colors.0}",
+            "${theme.primary()}",
+            "${0.primary}",
+        ] {
+            assert_eq!(
+                Parser::interpolation(m).map(|m| m.1),
+                Ok(&m[2..m.len() - 1])
+            );
+        }
+
+        assert!(Parser::interpolation("${theme.}").is_err());
+        assert!(Parser::interpolation("${.primary}").is_err());
+    }
+
     #[test]
     fn test_interpolation() {
         init();
@@ -1471,4 +1567,86 @@ mod tests {
 
         assert_eq!(parsed, expected);
     }
+
+    #[test]
+    fn test_container_and_layer_rules() -> Result<()> {
+        init();
+
+        let test_str = r#"
+                @layer base, components;
+                @layer base {
+                    @container sidebar (min-width: 400px) {
+                        display: flex;
+                    }
+                }
+            "#;
+        let parsed = Parser::parse(test_str)?;
+
+        let expected = Sheet::from(vec![
+            ScopeContent::Rule(Rule {
+                condition: vec!["@layer ".into(), "base, components".into()].into(),
+                content: vec![].into(),
+            }),
+            ScopeContent::Rule(Rule {
+                condition: vec!["@layer ".into(), "base".into()].into(),
+                content: vec![RuleBlockContent::Rule(
+                    Rule {
+                        condition: vec!["@container ".into(), "sidebar (min-width: 400px)".into()]
+                            .into(),
+                        content: vec![RuleBlockContent::Block(
+                            Block {
+                                condition: Cow::Borrowed(&[]),
+                                content: vec![StyleAttribute {
+                                    key: "display".into(),
+                                    value: vec!["flex".into()].into(),
+                                }
+                                .into()]
+                                .into(),
+                            }
+                            .into(),
+                        )]
+                        .into(),
+                    }
+                    .into(),
+                )]
+                .into(),
+            }),
+        ]);
+
+        assert_eq!(parsed, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_font_face_rule() -> Result<()> {
+        init();
+
+        let test_str = r#"
+                @font-face {
+                    font-family: "Open Sans";
+                    font-display: swap;
+                }
+            "#;
+        let parsed = Parser::parse(test_str)?;
+
+        let expected = Sheet::from(vec![ScopeContent::Rule(Rule {
+            condition: vec!["@font-face".into()].into(),
+            content: vec![
+                RuleBlockContent::StyleAttr(StyleAttribute {
+                    key: "font-family".into(),
+                    value: vec![r#""Open Sans""#.into()].into(),
+                }),
+                RuleBlockContent::StyleAttr(StyleAttribute {
+                    key: "font-display".into(),
+                    value: vec!["swap".into()].into(),
+                }),
+            ]
+            .into(),
+        })]);
+
+        assert_eq!(parsed, expected);
+
+        Ok(())
+    }
 }
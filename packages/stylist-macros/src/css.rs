@@ -1,8 +1,32 @@
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
 
 pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
-    let sheet_tokens = crate::sheet::macro_fn(input);
+    macro_fn_impl(input, true)
+}
+
+/// Creates a `StyleSource`.
+///
+/// `#![css_vars]` is only allowed if the `StyleSource` is returned to the user, who can apply the
+/// variables.
+pub(crate) fn macro_fn_impl(input: TokenStream, allow_css_vars: bool) -> TokenStream {
+    let (sheet_tokens, css_vars) = crate::sheet::macro_fn_with_vars(input, allow_css_vars);
+
+    let css_vars = css_vars.into_iter().map(|m| {
+        let name = m.name;
+        let expr = m.expr;
+        quote_spanned! {expr.span() =>
+            .with_var(
+                #name,
+                ::std::string::ToString::to_string(&(#expr) as &dyn ::std::fmt::Display),
+            )
+        }
+    });
 
-    quote! { ::stylist::StyleSource::from(#sheet_tokens) }
+    quote! {
+        ::stylist::StyleSource::from(#sheet_tokens)
+            .with_name(::std::module_path!())
+            #(#css_vars)*
+    }
 }
@@ -0,0 +1,279 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, Lit, Meta, NestedMeta, Path,
+    Type,
+};
+
+#[derive(Debug, Default)]
+struct CssVarsAttrs {
+    prefix: Option<String>,
+    rename: Option<String>,
+    nested: bool,
+    skip: bool,
+}
+
+impl CssVarsAttrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+
+        for attr in attrs.iter().filter(|m| m.path.is_ident("css_vars")) {
+            let list = match attr.parse_meta()? {
+                Meta::List(m) => m,
+                m => return Err(Error::new_spanned(m, "expected `#[css_vars(...)]`")),
+            };
+
+            for nested in list.nested.iter() {
+                match nested {
+                    NestedMeta::Meta(Meta::NameValue(m)) if m.path.is_ident("prefix") => {
+                        result.prefix = Some(Self::parse_str(&m.lit)?);
+                    }
+                    NestedMeta::Meta(Meta::NameValue(m)) if m.path.is_ident("rename") => {
+                        result.rename = Some(Self::parse_str(&m.lit)?);
+                    }
+                    NestedMeta::Meta(Meta::Path(m)) if m.is_ident("nested") => {
+                        result.nested = true;
+                    }
+                    NestedMeta::Meta(Meta::Path(m)) if m.is_ident("skip") => {
+                        result.skip = true;
+                    }
+                    m => {
+                        return Err(Error::new_spanned(
+                            m,
+                            "unknown attribute, expected one of `prefix`, `rename`, `nested` or \
+                             `skip`",
+                        ))
+                    }
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn parse_str(lit: &Lit) -> syn::Result<String> {
+        match lit {
+            Lit::Str(m) => Ok(m.value()),
+            m => Err(Error::new_spanned(m, "expected a string literal")),
+        }
+    }
+}
+
+// Converts an identifier to a name of a custom property (e.g.: `font_size` -> `font-size`,
+// `DesignTokens` -> `design-tokens` and `HTTPColor` -> `http-color`).
+//
+// A run of capitals is treated as one word, the last capital of a run starts a new word if it is
+// followed by a lowercase letter.
+fn to_kebab_case(ident: &Ident) -> String {
+    let s = ident.to_string();
+    let s = s.strip_prefix("r#").unwrap_or(&s);
+    let chars: Vec<char> = s.chars().collect();
+
+    let mut result = String::with_capacity(s.len());
+    for (index, &c) in chars.iter().enumerate() {
+        if c == '_' {
+            result.push('-');
+        } else if c.is_ascii_uppercase() {
+            let prev = index.checked_sub(1).map(|m| chars[m]);
+            let next = chars.get(index + 1);
+
+            let starts_word = match prev {
+                Some(m) if m.is_ascii_uppercase() => {
+                    matches!(next, Some(m) if m.is_ascii_lowercase())
+                }
+                Some(_) => true,
+                None => false,
+            };
+            if starts_word && !result.ends_with('-') {
+                result.push('-');
+            }
+            result.push(c.to_ascii_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+// Returns the path of the vars struct of a nested type (e.g.: `tokens::Spacing` ->
+// `tokens::SpacingVars`).
+fn vars_path_of(ty: &Type) -> syn::Result<Path> {
+    let path = match ty {
+        Type::Path(m) if m.qself.is_none() => &m.path,
+        m => {
+            return Err(Error::new_spanned(
+                m,
+                "nested custom properties must be a struct that derives `CssVars`",
+            ))
+        }
+    };
+
+    let mut path = path.clone();
+    if let Some(m) = path.segments.last_mut() {
+        m.ident = format_ident!("{}Vars", m.ident);
+        m.arguments = syn::PathArguments::None;
+    }
+
+    Ok(path)
+}
+
+fn derive_impl(input: DeriveInput) -> syn::Result<TokenStream> {
+    let DeriveInput {
+        attrs,
+        vis,
+        ident,
+        generics,
+        data,
+    } = input;
+
+    let fields = match data {
+        Data::Struct(m) => match m.fields {
+            Fields::Named(m) => m.named,
+            m => {
+                return Err(Error::new(
+                    m.span(),
+                    "`CssVars` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                ident.span(),
+                "`CssVars` can only be derived for structs with named fields",
+            ))
+        }
+    };
+
+    let struct_attrs = CssVarsAttrs::parse(&attrs)?;
+    let prefix = format!(
+        "--{}",
+        struct_attrs.prefix.unwrap_or_else(|| to_kebab_case(&ident))
+    );
+
+    let vars_ident = format_ident!("{}Vars", ident);
+
+    let mut push_vars = Vec::new();
+    let mut accessors = Vec::new();
+
+    for field in fields.iter() {
+        let attrs = CssVarsAttrs::parse(&field.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+
+        let field_ident = field.ident.as_ref().expect("named fields have identifiers");
+        let name = attrs.rename.unwrap_or_else(|| to_kebab_case(field_ident));
+        let field_vis = &field.vis;
+
+        if attrs.nested {
+            let nested_vars = vars_path_of(&field.ty)?;
+
+            push_vars.push(quote! {
+                vars.extend(::stylist::CssVars::css_vars_with_prefix(
+                    &self.#field_ident,
+                    &::std::format!("{}-{}", prefix, #name),
+                ));
+            });
+            accessors.push(quote! {
+                #[doc = ::std::concat!("Returns the custom properties of `", #name, "`.")]
+                #field_vis fn #field_ident(&self) -> #nested_vars {
+                    #nested_vars::with_prefix(::std::format!("{}-{}", self.prefix, #name))
+                }
+            });
+        } else {
+            push_vars.push(quote! {
+                vars.push((
+                    ::std::format!("{}-{}", prefix, #name),
+                    ::std::string::ToString::to_string(&self.#field_ident),
+                ));
+            });
+            accessors.push(quote! {
+                #[doc = ::std::concat!("Returns `var()` of the custom property of `", #name, "`.")]
+                #field_vis fn #field_ident(&self) -> ::std::string::String {
+                    ::std::format!("var({}-{})", self.prefix, #name)
+                }
+            });
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let vars_doc = format!(
+        "Typed references to the custom properties of [`{}`].",
+        ident
+    );
+
+    Ok(quote! {
+        impl #impl_generics ::stylist::CssVars for #ident #ty_generics #where_clause {
+            fn css_vars(&self) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+                ::stylist::CssVars::css_vars_with_prefix(self, #prefix)
+            }
+
+            fn css_vars_with_prefix(
+                &self,
+                prefix: &str,
+            ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+                #[allow(unused_mut)]
+                let mut vars = ::std::vec::Vec::new();
+                #(#push_vars)*
+                vars
+            }
+        }
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Returns typed references to the custom properties of this struct.
+            #vis fn vars() -> #vars_ident {
+                #vars_ident::with_prefix(#prefix)
+            }
+        }
+
+        #[doc = #vars_doc]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #vis struct #vars_ident {
+            prefix: ::std::borrow::Cow<'static, str>,
+        }
+
+        impl #vars_ident {
+            /// Creates references to custom properties with a prefix.
+            #vis fn with_prefix<P>(prefix: P) -> Self
+            where
+                P: ::std::convert::Into<::std::borrow::Cow<'static, str>>,
+            {
+                Self {
+                    prefix: prefix.into(),
+                }
+            }
+
+            #(#accessors)*
+        }
+    })
+}
+
+pub fn macro_fn(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    derive_impl(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::Span;
+
+    use super::*;
+
+    fn kebab(s: &str) -> String {
+        to_kebab_case(&Ident::new(s, Span::call_site()))
+    }
+
+    #[test]
+    fn test_kebab_case() {
+        assert_eq!(kebab("font_size"), "font-size");
+        assert_eq!(kebab("DesignTokens"), "design-tokens");
+        assert_eq!(kebab("HTTPColor"), "http-color");
+        assert_eq!(kebab("ColorRGB"), "color-rgb");
+        assert_eq!(kebab("UI"), "ui");
+    }
+}
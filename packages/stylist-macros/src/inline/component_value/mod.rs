@@ -113,6 +113,39 @@ impl ComponentValue {
         }
     }
 
+    /// Converts a value of an @-rule prelude into fragments.
+    ///
+    /// An identifier followed by parentheses is parsed as a function (e.g.: `and (...)`), but only
+    /// a few functions can appear in a prelude. The others are emitted with a space before the
+    /// parentheses, as the whitespace between them cannot be detected.
+    pub fn to_prelude_output_fragments(&self) -> Vec<OutputFragment> {
+        const PRELUDE_FUNCTIONS: &[&str] = &[
+            "font-format",
+            "font-tech",
+            "layer",
+            "media",
+            "scroll-state",
+            "selector",
+            "style",
+            "supports",
+            "url",
+        ];
+
+        match self {
+            Self::Function(FunctionToken { name, args, .. })
+                if !PRELUDE_FUNCTIONS.contains(&name.to_output_string().as_str()) =>
+            {
+                let mut output = vec![name.clone().into(), ' '.into(), '('.into()];
+                for c in args {
+                    output.extend(c.to_output_fragments());
+                }
+                output.push(')'.into());
+                output
+            }
+            _ => self.to_output_fragments(),
+        }
+    }
+
     // Overly simplified parsing of a css attribute
     #[must_use = "validation errors should not be discarded"]
     pub fn validate_attribute_token(&self) -> Vec<ParseError> {
@@ -148,6 +181,16 @@ impl ComponentValue {
         }
     }
 
+    /// Returns `true` if the value is an integer or a float literal.
+    pub fn is_number(&self) -> bool {
+        match self {
+            Self::Token(PreservedToken::Literal(l)) => {
+                matches!(Lit::new(l.clone()), Lit::Int(_) | Lit::Float(_))
+            }
+            _ => false,
+        }
+    }
+
     // Overly simplified version of parsing a css selector :)
     pub fn validate_selector_token(&self) -> ParseResult<Vec<ParseError>> {
         match self {
@@ -2,27 +2,55 @@ pub mod component_value;
 pub mod css_ident;
 
 mod parse;
+mod properties;
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
 use crate::output::{Reify, ReifyContext};
 use log::debug;
+pub use parse::CssVar;
 use parse::{CssRootNode, IntoOutputContext};
 use proc_macro2::TokenStream;
 
-pub fn macro_fn(input: TokenStream) -> TokenStream {
+pub fn macro_fn(input: TokenStream, allow_css_vars: bool) -> (TokenStream, Vec<CssVar>) {
+    let seed = {
+        let mut hasher = DefaultHasher::new();
+        // Identical invocations may share custom properties, as each element or class declares
+        // its own values.
+        input.to_string().hash(&mut hasher);
+        hasher.finish()
+    };
+
     let root = match syn::parse2::<CssRootNode>(input) {
         Ok(parsed) => parsed,
-        Err(failed) => return failed.to_compile_error(),
+        Err(failed) => return (failed.to_compile_error(), Vec::new()),
     };
 
     debug!("Parsed as: {:?}", root);
 
-    let mut into_output_ctx = IntoOutputContext::new();
+    let mut into_output_ctx = match root.css_vars() {
+        Some(m) if !allow_css_vars => {
+            let error = syn::Error::new_spanned(
+                m,
+                "`#![css_vars]` is only supported by `css!`, \
+                 the variables need to be applied with the returned `StyleSource`",
+            );
+            return (error.to_compile_error(), Vec::new());
+        }
+        Some(_) => IntoOutputContext::with_css_vars(seed),
+        None => IntoOutputContext::new(),
+    };
+    if !root.validates_properties() {
+        into_output_ctx = into_output_ctx.unchecked();
+    }
     let output_root = root.into_output(&mut into_output_ctx);
+    let css_vars = into_output_ctx.take_css_vars();
 
     if let Some(m) = into_output_ctx.into_compile_errors() {
-        m
+        (m, Vec::new())
     } else {
         let mut ctx = ReifyContext::new();
-        output_root.into_token_stream(&mut ctx)
+        (output_root.into_token_stream(&mut ctx), css_vars)
     }
 }
@@ -1,3 +1,5 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
 use syn::parse::{Error as ParseError, Parse, ParseBuffer, Result as ParseResult};
 use syn::spanned::Spanned;
 use syn::token;
@@ -7,7 +9,8 @@ use crate::inline::component_value::{
     ComponentValue, ComponentValueStream, InterpolatedExpression, PreservedToken,
 };
 use crate::inline::css_ident::CssIdent;
-use crate::output::{OutputAttribute, OutputCowString};
+use crate::inline::properties::validate_attribute;
+use crate::output::{OutputAttribute, OutputCowString, OutputFragment, Reify, ReifyContext};
 use crate::spacing_iterator::SpacedIterator;
 
 #[derive(Debug)]
@@ -93,15 +96,39 @@ impl ComponentValue {
     }
 }
 
+impl ToTokens for CssAttributeName {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Identifier(m) => m.to_tokens(tokens),
+            Self::Expr(m) => m.to_tokens(tokens),
+        }
+    }
+}
+
 impl CssAttribute {
+    pub fn name(&self) -> &CssAttributeName {
+        &self.name
+    }
+
     pub(super) fn into_output(self, ctx: &mut IntoOutputContext) -> OutputAttribute {
         ctx.extend_errors(self.value.errors);
 
-        let values = self
+        if let CssAttributeName::Identifier(ref name) = self.name {
+            if ctx.validates_properties() {
+                ctx.extend_errors(validate_attribute(name, &self.value.values));
+            }
+        }
+
+        let fragments: Vec<_> = self
             .value
             .values
             .into_iter()
             .flat_map(|p| p.to_output_fragments())
+            .collect();
+        let values = (0..fragments.len())
+            .map(|i| Self::maybe_to_css_var(&fragments, i, ctx))
+            .collect::<Vec<_>>()
+            .into_iter()
             .spaced_with(fragment_spacing)
             .collect();
         OutputAttribute {
@@ -111,6 +138,60 @@ impl CssAttribute {
     }
 }
 
+impl CssAttribute {
+    // Replaces dynamic interpolations with custom properties if enabled.
+    fn maybe_to_css_var(
+        fragments: &[OutputFragment],
+        index: usize,
+        ctx: &mut IntoOutputContext,
+    ) -> OutputFragment {
+        let fragment = fragments[index].clone();
+        if !ctx.uses_css_vars() {
+            return fragment;
+        }
+
+        let expr = match fragment {
+            OutputFragment::Expr(ref m) => m.clone(),
+            _ => return fragment,
+        };
+
+        let mut fragment_ctx = ReifyContext::new();
+        fragment.clone().into_token_stream(&mut fragment_ctx);
+        if fragment_ctx.is_static() {
+            return fragment;
+        }
+
+        // A custom property can only be substituted for complete tokens, `var(--s-*)px` is not a
+        // length.
+        let joins_prev = index
+            .checked_sub(1)
+            .and_then(|i| fragments.get(i))
+            .map(Self::joins_css_var)
+            .unwrap_or(false);
+        let joins_next = fragments
+            .get(index + 1)
+            .map(|m| Self::joins_css_var(m) || matches!(m, OutputFragment::Token(PreservedToken::Punct(ref p)) if p.as_char() == '%'))
+            .unwrap_or(false);
+        if joins_prev || joins_next {
+            ctx.push_error(ParseError::new_spanned(
+                &expr,
+                "an interpolation compiled to a custom property has to be a complete value, \
+                 e.g.: `${format!(\"{}px\", width)}` instead of `${width}px`",
+            ));
+        }
+
+        ctx.push_css_var(expr)
+    }
+
+    fn joins_css_var(fragment: &OutputFragment) -> bool {
+        matches!(
+            fragment,
+            OutputFragment::Token(PreservedToken::Ident(_))
+                | OutputFragment::Token(PreservedToken::Literal(_))
+        )
+    }
+}
+
 impl CssAttributeName {
     fn into_output(self) -> OutputCowString {
         match self {
@@ -1,7 +1,8 @@
-use crate::output::{OutputBlock, OutputRuleBlockContent};
+use crate::inline::component_value::PreservedToken;
+use crate::output::{OutputFragment, OutputRule, OutputScopeContent, OutputSelector};
 use syn::parse::{Parse, ParseBuffer, Result as ParseResult};
 
-use super::{CssAttribute, CssBlockQualifier, CssScope, IntoOutputContext};
+use super::{CssBlockQualifier, CssScope, IntoOutputContext};
 
 #[derive(Debug)]
 pub struct CssQualifiedRule {
@@ -18,27 +19,104 @@ impl Parse for CssQualifiedRule {
 }
 
 impl CssQualifiedRule {
-    pub fn into_output(self, ctx: &mut IntoOutputContext) -> OutputBlock {
-        let condition = self.qualifier.into_output(ctx);
-        let content = self.scope.into_rule_block_output(ctx);
+    /// Converts the block and the blocks nested in it into blocks with combined selectors.
+    pub fn into_nested_output(
+        self,
+        parents: &[OutputSelector],
+        ctx: &mut IntoOutputContext,
+    ) -> Vec<OutputScopeContent> {
+        let condition = nest_selectors(parents, self.qualifier.into_output(ctx));
+        self.scope.into_nested_output(&condition, ctx)
+    }
 
-        OutputBlock { condition, content }
+    // Into Output for a keyframe of `@keyframes` (e.g.: `50% { ... }`)
+    pub fn into_keyframe_output(self, ctx: &mut IntoOutputContext) -> OutputRule {
+        let condition = self.qualifier.into_keyframe_selector_output(ctx);
+        let content = self.scope.into_attributes_output(ctx, "a keyframe");
+
+        OutputRule { condition, content }
     }
+}
 
-    // Into Output for a dangling block
-    pub fn into_dangling_output(
-        attrs: Vec<CssAttribute>,
-        ctx: &mut IntoOutputContext,
-    ) -> OutputBlock {
-        let mut output_attrs = Vec::new();
+fn is_current_selector(fragment: &OutputFragment) -> bool {
+    matches!(fragment, OutputFragment::Token(PreservedToken::Punct(ref p)) if p.as_char() == '&')
+}
+
+// Combines the selectors of a nested block with the selectors of its parent block.
+//
+// The current selector (`&`) in a nested selector is replaced with the parent selector (e.g.:
+// `&:hover` -> `.parent:hover`). Other selectors select descendants of the parent (e.g.: `.child`
+// -> `.parent .child`).
+//
+// Each occurrence of the current selector stands for any of the parent selectors, like
+// `:is(.a, .b)` in native CSS nesting. So every combination of parents is emitted (e.g.:
+// `.a, .b { & + & {} }` -> `.a + .a, .a + .b, .b + .a, .b + .b`), which keeps the specificity of
+// each parent selector.
+fn nest_selectors(
+    parents: &[OutputSelector],
+    children: Vec<OutputSelector>,
+) -> Vec<OutputSelector> {
+    if parents.is_empty() {
+        return children;
+    }
+    if children.is_empty() {
+        return parents.to_vec();
+    }
+
+    let mut selectors = Vec::new();
+    for child in children.iter() {
+        let current_count = child
+            .selectors
+            .iter()
+            .filter(|m| is_current_selector(m))
+            .count();
+
+        if current_count == 0 {
+            for parent in parents {
+                let mut fragments = parent.selectors.clone();
+                fragments.push(' '.into());
+                fragments.extend(child.selectors.iter().cloned());
 
-        for attr in attrs {
-            output_attrs.push(OutputRuleBlockContent::StyleAttr(attr.into_output(ctx)))
+                selectors.push(OutputSelector {
+                    selectors: fragments,
+                });
+            }
+            continue;
         }
 
-        OutputBlock {
-            condition: Vec::new(),
-            content: output_attrs,
+        // The index of the parent used for each occurrence of the current selector.
+        let mut indices = vec![0; current_count];
+        loop {
+            let mut fragments = Vec::new();
+            let mut current_index = indices.iter();
+            for fragment in child.selectors.iter() {
+                if is_current_selector(fragment) {
+                    let index = *current_index.next().expect("counted above");
+                    fragments.extend(parents[index].selectors.iter().cloned());
+                } else {
+                    fragments.push(fragment.clone());
+                }
+            }
+
+            selectors.push(OutputSelector {
+                selectors: fragments,
+            });
+
+            // Advances to the next combination, the last occurrence changes fastest.
+            let mut advanced = false;
+            for index in indices.iter_mut().rev() {
+                *index += 1;
+                if *index < parents.len() {
+                    advanced = true;
+                    break;
+                }
+                *index = 0;
+            }
+            if !advanced {
+                break;
+            }
         }
     }
+
+    selectors
 }
@@ -1,5 +1,9 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
 use proc_macro2::TokenStream;
 use syn::parse::Error as ParseError;
+use syn::Expr;
 
 use crate::output::OutputFragment;
 
@@ -19,9 +23,24 @@ pub use rule::CssAtRule;
 pub use scope::CssScope;
 pub use scope_content::CssScopeContent;
 
+/// A dynamic interpolation that has been compiled to a CSS custom property.
+#[derive(Debug)]
+pub struct CssVar {
+    pub name: String,
+    pub expr: Expr,
+}
+
+#[derive(Debug)]
+struct CssVarsContext {
+    seed: u64,
+    vars: Vec<CssVar>,
+}
+
 #[derive(Debug, Default)]
 pub struct IntoOutputContext {
     errors: Vec<ParseError>,
+    css_vars: Option<CssVarsContext>,
+    unchecked: bool,
 }
 
 impl IntoOutputContext {
@@ -29,6 +48,71 @@ impl IntoOutputContext {
         Self::default()
     }
 
+    /// Creates a context that compiles dynamic interpolations in attribute values to CSS custom
+    /// properties.
+    ///
+    /// Names of custom properties are derived from the seed.
+    pub fn with_css_vars(seed: u64) -> Self {
+        Self {
+            css_vars: Some(CssVarsContext {
+                seed,
+                vars: Vec::new(),
+            }),
+            ..Self::default()
+        }
+    }
+
+    /// Disables the validation of property names and values.
+    pub fn unchecked(mut self) -> Self {
+        self.unchecked = true;
+        self
+    }
+
+    pub fn validates_properties(&self) -> bool {
+        !self.unchecked
+    }
+
+    /// Runs `f` without validating property names and values.
+    pub fn unchecked_scope<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        let unchecked = std::mem::replace(&mut self.unchecked, true);
+        let result = f(self);
+        self.unchecked = unchecked;
+
+        result
+    }
+
+    pub fn uses_css_vars(&self) -> bool {
+        self.css_vars.is_some()
+    }
+
+    /// Registers a custom property for an expression and returns a reference to it.
+    pub fn push_css_var(&mut self, expr: Expr) -> OutputFragment {
+        let css_vars = self
+            .css_vars
+            .as_mut()
+            .expect("css vars are not enabled for this context");
+
+        let mut hasher = DefaultHasher::new();
+        css_vars.seed.hash(&mut hasher);
+        css_vars.vars.len().hash(&mut hasher);
+        let name = format!("--s-{:08x}", hasher.finish() as u32);
+
+        let fragment = OutputFragment::Str(format!("var({})", name));
+        css_vars.vars.push(CssVar { name, expr });
+
+        fragment
+    }
+
+    pub fn take_css_vars(&mut self) -> Vec<CssVar> {
+        self.css_vars
+            .as_mut()
+            .map(|m| std::mem::take(&mut m.vars))
+            .unwrap_or_default()
+    }
+
     pub fn extend_errors<I>(&mut self, errors: I)
     where
         I: IntoIterator<Item = ParseError>,
@@ -1,12 +1,12 @@
 use super::super::component_value::{ComponentValue, ComponentValueStream, PreservedToken};
 use super::{fragment_spacing, IntoOutputContext};
-use crate::output::OutputSelector;
+use crate::output::{OutputFragment, OutputSelector};
 use crate::spacing_iterator::SpacedIterator;
 use itertools::Itertools;
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use syn::parse::{Error as ParseError, Parse, ParseBuffer, Result as ParseResult};
-use syn::token;
+use syn::{token, Token};
 
 #[derive(Debug, Clone, Default)]
 pub struct CssBlockQualifier {
@@ -27,11 +27,20 @@ impl Parse for CssBlockQualifier {
             let next_token = component_iter
                 .next()
                 .ok_or_else(|| input.error("ScopeQualifier: unexpected end of input"))??;
-            let token_errors = next_token.validate_selector_token()?;
-            if token_errors.is_empty() {
-                qualifiers.push(next_token);
+
+            // Percentages are used as selectors of frames (e.g.: `50% { ... }`).
+            if next_token.is_number() && input.peek(Token![%]) {
+                let percent = component_iter
+                    .next()
+                    .ok_or_else(|| input.error("ScopeQualifier: unexpected end of input"))??;
+                qualifiers.extend([next_token, percent]);
+                continue;
             }
-            errors.extend(token_errors);
+
+            // Invalid tokens are kept, as the qualifier may be the selector of a keyframe, which
+            // is validated differently.
+            errors.extend(next_token.validate_selector_token()?);
+            qualifiers.push(next_token);
         }
         Ok(Self { qualifiers, errors })
     }
@@ -46,6 +55,55 @@ impl ToTokens for CssBlockQualifier {
 }
 
 impl CssBlockQualifier {
+    // Keyframe selectors are `from`, `to`, percentages or interpolated expressions.
+    fn validate_keyframe_selector(&self) -> Vec<ParseError> {
+        let is_percent = |q: Option<&ComponentValue>| matches!(q, Some(ComponentValue::Token(PreservedToken::Punct(ref p))) if p.as_char() == '%');
+
+        let mut errors = Vec::new();
+        for (index, q) in self.qualifiers.iter().enumerate() {
+            let is_valid = match q {
+                ComponentValue::Token(PreservedToken::Ident(ref m)) => {
+                    matches!(
+                        m.to_output_string().to_ascii_lowercase().as_str(),
+                        "from" | "to"
+                    )
+                }
+                ComponentValue::Token(PreservedToken::Punct(ref p)) if p.as_char() == ',' => true,
+                ComponentValue::Expr(_) => true,
+                m if m.is_number() => is_percent(self.qualifiers.get(index + 1)),
+                m => is_percent(Some(m)) && index > 0 && self.qualifiers[index - 1].is_number(),
+            };
+
+            if !is_valid {
+                errors.push(ParseError::new_spanned(
+                    q,
+                    "expected a keyframe selector (`from`, `to` or a percentage)",
+                ));
+            }
+        }
+
+        errors
+    }
+
+    /// Converts the qualifier of a keyframe (e.g.: `from, 50%`) into a rule condition.
+    pub fn into_keyframe_selector_output(
+        mut self,
+        ctx: &mut IntoOutputContext,
+    ) -> Vec<OutputFragment> {
+        // The errors of a selector do not apply to a keyframe selector.
+        self.errors = self.validate_keyframe_selector();
+
+        let mut condition = Vec::new();
+        for (index, selector) in self.into_output(ctx).into_iter().enumerate() {
+            if index > 0 {
+                condition.push(OutputFragment::Str(", ".to_string()));
+            }
+            condition.extend(selector.selectors);
+        }
+
+        condition
+    }
+
     pub fn into_output(self, ctx: &mut IntoOutputContext) -> Vec<OutputSelector> {
         ctx.extend_errors(self.errors);
 
@@ -1,52 +1,63 @@
-use std::mem;
-
-use super::{CssAttribute, CssQualifiedRule, CssScopeContent, IntoOutputContext};
-use crate::output::{OutputScopeContent, OutputSheet};
-use syn::parse::{Parse, ParseBuffer, Result as ParseResult};
+use super::{CssScopeContent, IntoOutputContext};
+use crate::output::OutputSheet;
+use syn::parse::{Error as ParseError, Parse, ParseBuffer, Result as ParseResult};
+use syn::{Attribute, Token};
 
 #[derive(Debug)]
 pub struct CssRootNode {
+    // `#![css_vars]`
+    css_vars: Option<Attribute>,
+    // `#![unchecked]`
+    unchecked: Option<Attribute>,
     contents: Vec<CssScopeContent>,
 }
 
 impl Parse for CssRootNode {
     fn parse(input: &ParseBuffer) -> ParseResult<Self> {
+        let mut css_vars = None;
+        let mut unchecked = None;
+        if input.peek(Token![#]) && input.peek2(Token![!]) {
+            for attr in input.call(Attribute::parse_inner)? {
+                let slot = match attr.path.get_ident() {
+                    Some(m) if m == "css_vars" => Some(&mut css_vars),
+                    Some(m) if m == "unchecked" => Some(&mut unchecked),
+                    _ => None,
+                };
+
+                match slot {
+                    Some(slot) if attr.tokens.is_empty() => *slot = Some(attr),
+                    _ => {
+                        return Err(ParseError::new_spanned(
+                            attr,
+                            "unknown attribute, expected `#![css_vars]` or `#![unchecked]`",
+                        ))
+                    }
+                }
+            }
+        }
+
         let contents = CssScopeContent::consume_list_of_rules(input)?;
-        Ok(Self { contents })
+        Ok(Self {
+            css_vars,
+            unchecked,
+            contents,
+        })
     }
 }
 
 impl CssRootNode {
-    pub fn into_output(self, ctx: &mut IntoOutputContext) -> OutputSheet {
-        let mut contents = Vec::new();
-
-        let mut attrs: Vec<CssAttribute> = Vec::new();
-        let flush_attrs = |attrs: &mut Vec<CssAttribute>,
-                           contents: &mut Vec<OutputScopeContent>,
-                           ctx: &mut IntoOutputContext| {
-            if !attrs.is_empty() {
-                contents.push(OutputScopeContent::Block(
-                    CssQualifiedRule::into_dangling_output(mem::take(attrs), ctx),
-                ));
-            }
-        };
-
-        for scope in self.contents {
-            match scope {
-                CssScopeContent::Attribute(m) => attrs.push(m),
-                CssScopeContent::AtRule(m) => {
-                    flush_attrs(&mut attrs, &mut contents, ctx);
-                    contents.push(OutputScopeContent::Rule(m.into_rule_output(ctx)));
-                }
+    /// Returns the `#![css_vars]` attribute, if present.
+    pub fn css_vars(&self) -> Option<&Attribute> {
+        self.css_vars.as_ref()
+    }
 
-                CssScopeContent::Nested(m) => {
-                    flush_attrs(&mut attrs, &mut contents, ctx);
-                    contents.push(OutputScopeContent::Block(m.into_output(ctx)));
-                }
-            }
-        }
+    /// Returns `true` if properties should be validated, i.e.: `#![unchecked]` is not present.
+    pub fn validates_properties(&self) -> bool {
+        self.unchecked.is_none()
+    }
 
-        flush_attrs(&mut attrs, &mut contents, ctx);
+    pub fn into_output(self, ctx: &mut IntoOutputContext) -> OutputSheet {
+        let contents = CssScopeContent::list_into_nested_output(self.contents, &[], ctx);
         OutputSheet { contents }
     }
 }
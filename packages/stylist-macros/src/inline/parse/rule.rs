@@ -4,7 +4,7 @@ use syn::token;
 use super::super::component_value::{ComponentValue, ComponentValueStream};
 use super::super::css_ident::CssIdent;
 use super::{fragment_spacing, CssScope, IntoOutputContext};
-use crate::output::{OutputFragment, OutputRule};
+use crate::output::{OutputFragment, OutputRule, OutputRuleBlockContent, OutputSelector};
 use crate::spacing_iterator::SpacedIterator;
 
 #[derive(Debug)]
@@ -13,10 +13,33 @@ pub enum CssAtRuleContent {
     Empty(token::Semi),
 }
 
+/// The type of an @-rule, which determines what the rule can contain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CssAtRuleKind {
+    // `@media`, `@supports`, `@container` and `@layer` contain the contents of a scope.
+    Conditional,
+    // `@keyframes` contains keyframes.
+    Keyframes,
+    // `@font-face` contains descriptors.
+    FontFace,
+}
+
+impl CssAtRuleKind {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "media" | "supports" | "container" | "layer" => Some(Self::Conditional),
+            "keyframes" => Some(Self::Keyframes),
+            "font-face" => Some(Self::FontFace),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct CssAtRule {
     _at: token::At,
-    name: CssIdent,
+    pub name: CssIdent,
+    kind: CssAtRuleKind,
     prelude: Vec<ComponentValue>,
     contents: CssAtRuleContent,
     errors: Vec<ParseError>,
@@ -33,14 +56,13 @@ impl Parse for CssAtRule {
         let mut errors = vec![];
 
         // Recognize the type of @-rule
-        // TODO: be sensitive to this detected type when validating the prelude and contained
-        // attributes
-        if !["media", "supports"].contains(&name.to_output_string().as_str()) {
+        let kind = CssAtRuleKind::from_name(&name.to_output_string()).unwrap_or_else(|| {
             errors.push(ParseError::new_spanned(
                 &name,
                 format!("@-rule '{}' not supported", name),
             ));
-        }
+            CssAtRuleKind::Conditional
+        });
 
         let contents = loop {
             if input.peek(token::Semi) {
@@ -57,9 +79,32 @@ impl Parse for CssAtRule {
             prelude.push(next_token);
         };
 
+        match (kind, &contents) {
+            (CssAtRuleKind::Keyframes | CssAtRuleKind::FontFace, CssAtRuleContent::Empty(m)) => {
+                errors.push(ParseError::new_spanned(
+                    m,
+                    format!("expected a block after `@{}`", name),
+                ));
+            }
+            (CssAtRuleKind::Keyframes, _) if prelude.is_empty() => {
+                errors.push(ParseError::new_spanned(
+                    &name,
+                    "expected the name of the animation after `@keyframes`",
+                ));
+            }
+            (CssAtRuleKind::FontFace, _) if !prelude.is_empty() => {
+                errors.push(ParseError::new_spanned(
+                    &prelude[0],
+                    "unexpected prelude, `@font-face` has to be followed by a block",
+                ));
+            }
+            _ => {}
+        }
+
         Ok(Self {
             _at: at,
             name,
+            kind,
             prelude,
             contents,
             errors,
@@ -70,41 +115,61 @@ impl Parse for CssAtRule {
 impl CssAtRule {
     pub fn condition_output(&self) -> Vec<OutputFragment> {
         let mut prelude = vec![OutputFragment::Str(format!(
-            "@{} ",
+            "@{}",
             self.name.to_output_string()
         ))];
+        if !self.prelude.is_empty() {
+            prelude.push(' '.into());
+        }
         prelude.extend(
             self.prelude
                 .clone()
                 .into_iter()
-                .flat_map(|p| p.to_output_fragments())
+                .flat_map(|p| p.to_prelude_output_fragments())
                 .spaced_with(fragment_spacing),
         );
 
         prelude
     }
 
-    pub fn into_rule_output(self, ctx: &mut IntoOutputContext) -> OutputRule {
-        let condition = self.condition_output();
+    /// Converts the rule, attributes in the rule apply to the given selectors.
+    pub fn into_nested_output(
+        self,
+        condition: &[OutputSelector],
+        ctx: &mut IntoOutputContext,
+    ) -> OutputRule {
+        let rule_condition = self.condition_output();
+        // Properties of unsupported rules are not validated as the rule is already an error.
+        let supported = self.errors.is_empty();
         ctx.extend_errors(self.errors);
 
-        OutputRule {
-            condition,
-            content: match self.contents {
-                CssAtRuleContent::Scope(m) => m.into_rule_output(ctx),
-                CssAtRuleContent::Empty(_) => Vec::new(),
-            },
+        // Keyframes and font faces are not applied to the element, they can only appear at the
+        // top level of a stylesheet or in conditional rules.
+        if self.kind != CssAtRuleKind::Conditional && !condition.is_empty() {
+            ctx.push_error(ParseError::new_spanned(
+                &self.name,
+                format!("`@{}` can not be nested in a block", self.name),
+            ));
         }
-    }
 
-    pub fn into_rule_block_output(self, ctx: &mut IntoOutputContext) -> OutputRule {
-        let condition = self.condition_output();
-        ctx.extend_errors(self.errors);
+        let nested_output = |m: CssScope, ctx: &mut IntoOutputContext| {
+            m.into_nested_output(condition, ctx)
+                .into_iter()
+                .map(OutputRuleBlockContent::from)
+                .collect()
+        };
 
         OutputRule {
-            condition,
+            condition: rule_condition,
             content: match self.contents {
-                CssAtRuleContent::Scope(m) => m.into_rule_block_output(ctx),
+                CssAtRuleContent::Scope(m) if !supported => {
+                    ctx.unchecked_scope(|ctx| nested_output(m, ctx))
+                }
+                CssAtRuleContent::Scope(m) => match self.kind {
+                    CssAtRuleKind::Conditional => nested_output(m, ctx),
+                    CssAtRuleKind::Keyframes => m.into_keyframes_output(ctx),
+                    CssAtRuleKind::FontFace => m.into_attributes_output(ctx, "`@font-face`"),
+                },
                 CssAtRuleContent::Empty(_) => Vec::new(),
             },
         }
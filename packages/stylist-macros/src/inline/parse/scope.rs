@@ -1,10 +1,8 @@
-use std::mem;
-
 use syn::parse::{Error as ParseError, Parse, ParseBuffer, Result as ParseResult};
 use syn::{braced, token};
 
-use super::{CssAttribute, CssQualifiedRule, CssScopeContent, IntoOutputContext};
-use crate::output::OutputRuleBlockContent;
+use super::{CssScopeContent, IntoOutputContext};
+use crate::output::{OutputRuleBlockContent, OutputScopeContent, OutputSelector};
 
 #[derive(Debug)]
 pub struct CssScope {
@@ -25,63 +23,72 @@ impl Parse for CssScope {
 }
 
 impl CssScope {
-    pub fn into_rule_output(self, ctx: &mut IntoOutputContext) -> Vec<OutputRuleBlockContent> {
-        let mut attrs = Vec::new();
-        let mut contents = Vec::new();
+    /// Converts the contents of the scope, see [`CssScopeContent::list_into_nested_output`].
+    pub fn into_nested_output(
+        self,
+        condition: &[OutputSelector],
+        ctx: &mut IntoOutputContext,
+    ) -> Vec<OutputScopeContent> {
+        CssScopeContent::list_into_nested_output(self.contents, condition, ctx)
+    }
 
-        let flush_attrs = |attrs: &mut Vec<CssAttribute>,
-                           contents: &mut Vec<OutputRuleBlockContent>,
-                           ctx: &mut IntoOutputContext| {
-            if !attrs.is_empty() {
-                contents.push(OutputRuleBlockContent::Block(Box::new(
-                    CssQualifiedRule::into_dangling_output(mem::take(attrs), ctx),
-                )));
-            }
-        };
+    /// Converts the contents of a rule that can only contain attributes (e.g.: `@font-face`).
+    pub fn into_attributes_output(
+        self,
+        ctx: &mut IntoOutputContext,
+        owner: &str,
+    ) -> Vec<OutputRuleBlockContent> {
+        let mut contents = Vec::new();
 
         for scope in self.contents {
             match scope {
-                CssScopeContent::Attribute(m) => attrs.push(m),
+                CssScopeContent::Attribute(m) => {
+                    contents.push(OutputRuleBlockContent::StyleAttr(m.into_output(ctx)))
+                }
+
                 CssScopeContent::AtRule(m) => {
-                    flush_attrs(&mut attrs, &mut contents, ctx);
-                    contents.push(OutputRuleBlockContent::Rule(Box::new(
-                        m.into_rule_output(ctx),
-                    )));
+                    ctx.push_error(ParseError::new_spanned(
+                        m.name,
+                        format!("{} can only contain attributes", owner),
+                    ));
                 }
+
                 CssScopeContent::Nested(m) => {
-                    flush_attrs(&mut attrs, &mut contents, ctx);
-                    contents.push(OutputRuleBlockContent::Block(Box::new(m.into_output(ctx))));
+                    ctx.push_error(ParseError::new_spanned(
+                        m.qualifier,
+                        format!("{} can only contain attributes", owner),
+                    ));
                 }
             }
         }
 
-        flush_attrs(&mut attrs, &mut contents, ctx);
-
         contents
     }
 
-    pub fn into_rule_block_output(
-        self,
-        ctx: &mut IntoOutputContext,
-    ) -> Vec<OutputRuleBlockContent> {
+    /// Converts the contents of a `@keyframes` rule.
+    pub fn into_keyframes_output(self, ctx: &mut IntoOutputContext) -> Vec<OutputRuleBlockContent> {
         let mut contents = Vec::new();
 
         for scope in self.contents {
             match scope {
-                CssScopeContent::Attribute(m) => {
-                    contents.push(OutputRuleBlockContent::StyleAttr(m.into_output(ctx)))
-                }
-
-                CssScopeContent::AtRule(m) => {
+                CssScopeContent::Nested(m) => {
                     contents.push(OutputRuleBlockContent::Rule(Box::new(
-                        m.into_rule_block_output(ctx),
+                        m.into_keyframe_output(ctx),
                     )));
                 }
 
-                CssScopeContent::Nested(m) => {
+                CssScopeContent::Attribute(m) => {
                     ctx.push_error(ParseError::new_spanned(
-                        m.qualifier,
-                        "Can not nest qualified blocks (yet)",
+                        m.name(),
+                        "expected a keyframe, attributes have to be in a keyframe \
+                         (e.g.: `from { ... }`)",
+                    ));
+                }
+
+                CssScopeContent::AtRule(m) => {
+                    ctx.push_error(ParseError::new_spanned(
+                        m.name,
+                        "expected a keyframe, `@keyframes` can only contain keyframes",
                     ));
                 }
             }
@@ -1,8 +1,11 @@
+use std::mem;
+
 use itertools::Itertools;
 use syn::parse::{Parse, ParseBuffer, Result as ParseResult};
 
 use super::super::component_value::{ComponentValue, ComponentValueStream, PreservedToken};
-use super::{CssAtRule, CssAttribute, CssQualifiedRule};
+use super::{CssAtRule, CssAttribute, CssQualifiedRule, IntoOutputContext};
+use crate::output::{OutputBlock, OutputRuleBlockContent, OutputScopeContent, OutputSelector};
 
 #[derive(Debug)]
 pub enum CssScopeContent {
@@ -56,4 +59,50 @@ impl CssScopeContent {
         }
         Ok(contents)
     }
+
+    /// Converts a list of contents whose attributes apply to the given selectors.
+    ///
+    /// Blocks nested in the scope are expanded into blocks with the combined selectors and rules
+    /// are hoisted next to them, so the contents can be placed at the top level of a stylesheet
+    /// or in a rule. An empty condition refers to the element of the style.
+    pub fn list_into_nested_output(
+        list: Vec<Self>,
+        condition: &[OutputSelector],
+        ctx: &mut IntoOutputContext,
+    ) -> Vec<OutputScopeContent> {
+        let mut attrs = Vec::new();
+        let mut contents = Vec::new();
+
+        let flush_attrs = |attrs: &mut Vec<OutputRuleBlockContent>,
+                           contents: &mut Vec<OutputScopeContent>| {
+            if !attrs.is_empty() {
+                contents.push(OutputScopeContent::Block(OutputBlock {
+                    condition: condition.to_vec(),
+                    content: mem::take(attrs),
+                }));
+            }
+        };
+
+        for scope in list {
+            match scope {
+                Self::Attribute(m) => {
+                    attrs.push(OutputRuleBlockContent::StyleAttr(m.into_output(ctx)))
+                }
+                Self::AtRule(m) => {
+                    flush_attrs(&mut attrs, &mut contents);
+                    contents.push(OutputScopeContent::Rule(
+                        m.into_nested_output(condition, ctx),
+                    ));
+                }
+                Self::Nested(m) => {
+                    flush_attrs(&mut attrs, &mut contents);
+                    contents.extend(m.into_nested_output(condition, ctx));
+                }
+            }
+        }
+
+        flush_attrs(&mut attrs, &mut contents);
+
+        contents
+    }
 }
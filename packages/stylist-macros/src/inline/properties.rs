@@ -0,0 +1,833 @@
+//! A database of known CSS properties to validate attributes at compile time.
+
+use syn::parse::Error as ParseError;
+
+use super::component_value::{ComponentValue, PreservedToken};
+use super::css_ident::CssIdent;
+
+// Known properties and descriptors of at-rules (e.g.: `@font-face`), sorted for binary search.
+const PROPERTIES: &[&str] = &[
+    "accent-color",
+    "additive-symbols",
+    "align-content",
+    "align-items",
+    "align-self",
+    "align-tracks",
+    "all",
+    "anchor-name",
+    "anchor-scope",
+    "animation",
+    "animation-composition",
+    "animation-delay",
+    "animation-direction",
+    "animation-duration",
+    "animation-fill-mode",
+    "animation-iteration-count",
+    "animation-name",
+    "animation-play-state",
+    "animation-timeline",
+    "animation-timing-function",
+    "appearance",
+    "ascent-override",
+    "aspect-ratio",
+    "backdrop-filter",
+    "backface-visibility",
+    "background",
+    "background-attachment",
+    "background-blend-mode",
+    "background-clip",
+    "background-color",
+    "background-image",
+    "background-origin",
+    "background-position",
+    "background-position-x",
+    "background-position-y",
+    "background-repeat",
+    "background-size",
+    "base-palette",
+    "bleed",
+    "block-size",
+    "border",
+    "border-block",
+    "border-block-color",
+    "border-block-end",
+    "border-block-end-color",
+    "border-block-end-style",
+    "border-block-end-width",
+    "border-block-start",
+    "border-block-start-color",
+    "border-block-start-style",
+    "border-block-start-width",
+    "border-block-style",
+    "border-block-width",
+    "border-bottom",
+    "border-bottom-color",
+    "border-bottom-left-radius",
+    "border-bottom-right-radius",
+    "border-bottom-style",
+    "border-bottom-width",
+    "border-collapse",
+    "border-color",
+    "border-end-end-radius",
+    "border-end-start-radius",
+    "border-image",
+    "border-image-outset",
+    "border-image-repeat",
+    "border-image-slice",
+    "border-image-source",
+    "border-image-width",
+    "border-inline",
+    "border-inline-color",
+    "border-inline-end",
+    "border-inline-end-color",
+    "border-inline-end-style",
+    "border-inline-end-width",
+    "border-inline-start",
+    "border-inline-start-color",
+    "border-inline-start-style",
+    "border-inline-start-width",
+    "border-inline-style",
+    "border-inline-width",
+    "border-left",
+    "border-left-color",
+    "border-left-style",
+    "border-left-width",
+    "border-radius",
+    "border-right",
+    "border-right-color",
+    "border-right-style",
+    "border-right-width",
+    "border-spacing",
+    "border-start-end-radius",
+    "border-start-start-radius",
+    "border-style",
+    "border-top",
+    "border-top-color",
+    "border-top-left-radius",
+    "border-top-right-radius",
+    "border-top-style",
+    "border-top-width",
+    "border-width",
+    "bottom",
+    "box-decoration-break",
+    "box-shadow",
+    "box-sizing",
+    "break-after",
+    "break-before",
+    "break-inside",
+    "caption-side",
+    "caret",
+    "caret-color",
+    "caret-shape",
+    "clear",
+    "clip",
+    "clip-path",
+    "clip-rule",
+    "color",
+    "color-interpolation",
+    "color-interpolation-filters",
+    "color-scheme",
+    "column-count",
+    "column-fill",
+    "column-gap",
+    "column-rule",
+    "column-rule-color",
+    "column-rule-style",
+    "column-rule-width",
+    "column-span",
+    "column-width",
+    "columns",
+    "contain",
+    "contain-intrinsic-block-size",
+    "contain-intrinsic-height",
+    "contain-intrinsic-inline-size",
+    "contain-intrinsic-size",
+    "contain-intrinsic-width",
+    "container",
+    "container-name",
+    "container-type",
+    "content",
+    "content-visibility",
+    "counter-increment",
+    "counter-reset",
+    "counter-set",
+    "cursor",
+    "cx",
+    "cy",
+    "d",
+    "descent-override",
+    "direction",
+    "display",
+    "dominant-baseline",
+    "dynamic-range-limit",
+    "empty-cells",
+    "fallback",
+    "field-sizing",
+    "fill",
+    "fill-opacity",
+    "fill-rule",
+    "filter",
+    "flex",
+    "flex-basis",
+    "flex-direction",
+    "flex-flow",
+    "flex-grow",
+    "flex-shrink",
+    "flex-wrap",
+    "float",
+    "flood-color",
+    "flood-opacity",
+    "font",
+    "font-display",
+    "font-family",
+    "font-feature-settings",
+    "font-kerning",
+    "font-language-override",
+    "font-optical-sizing",
+    "font-palette",
+    "font-size",
+    "font-size-adjust",
+    "font-stretch",
+    "font-style",
+    "font-synthesis",
+    "font-synthesis-position",
+    "font-synthesis-small-caps",
+    "font-synthesis-style",
+    "font-synthesis-weight",
+    "font-variant",
+    "font-variant-alternates",
+    "font-variant-caps",
+    "font-variant-east-asian",
+    "font-variant-emoji",
+    "font-variant-ligatures",
+    "font-variant-numeric",
+    "font-variant-position",
+    "font-variation-settings",
+    "font-weight",
+    "forced-color-adjust",
+    "gap",
+    "grid",
+    "grid-area",
+    "grid-auto-columns",
+    "grid-auto-flow",
+    "grid-auto-rows",
+    "grid-column",
+    "grid-column-end",
+    "grid-column-gap",
+    "grid-column-start",
+    "grid-gap",
+    "grid-row",
+    "grid-row-end",
+    "grid-row-gap",
+    "grid-row-start",
+    "grid-template",
+    "grid-template-areas",
+    "grid-template-columns",
+    "grid-template-rows",
+    "hanging-punctuation",
+    "height",
+    "hyphenate-character",
+    "hyphenate-limit-chars",
+    "hyphens",
+    "image-orientation",
+    "image-rendering",
+    "image-resolution",
+    "inherits",
+    "initial-letter",
+    "initial-value",
+    "inline-size",
+    "inset",
+    "inset-block",
+    "inset-block-end",
+    "inset-block-start",
+    "inset-inline",
+    "inset-inline-end",
+    "inset-inline-start",
+    "interpolate-size",
+    "isolation",
+    "justify-content",
+    "justify-items",
+    "justify-self",
+    "justify-tracks",
+    "left",
+    "letter-spacing",
+    "lighting-color",
+    "line-break",
+    "line-clamp",
+    "line-gap-override",
+    "line-height",
+    "line-height-step",
+    "list-style",
+    "list-style-image",
+    "list-style-position",
+    "list-style-type",
+    "margin",
+    "margin-block",
+    "margin-block-end",
+    "margin-block-start",
+    "margin-bottom",
+    "margin-inline",
+    "margin-inline-end",
+    "margin-inline-start",
+    "margin-left",
+    "margin-right",
+    "margin-top",
+    "marker",
+    "marker-end",
+    "marker-mid",
+    "marker-start",
+    "marks",
+    "mask",
+    "mask-border",
+    "mask-border-mode",
+    "mask-border-outset",
+    "mask-border-repeat",
+    "mask-border-slice",
+    "mask-border-source",
+    "mask-border-width",
+    "mask-clip",
+    "mask-composite",
+    "mask-image",
+    "mask-mode",
+    "mask-origin",
+    "mask-position",
+    "mask-repeat",
+    "mask-size",
+    "mask-type",
+    "math-depth",
+    "math-shift",
+    "math-style",
+    "max-block-size",
+    "max-height",
+    "max-inline-size",
+    "max-width",
+    "min-block-size",
+    "min-height",
+    "min-inline-size",
+    "min-width",
+    "mix-blend-mode",
+    "negative",
+    "object-fit",
+    "object-position",
+    "offset",
+    "offset-anchor",
+    "offset-distance",
+    "offset-path",
+    "offset-position",
+    "offset-rotate",
+    "opacity",
+    "order",
+    "orphans",
+    "outline",
+    "outline-color",
+    "outline-offset",
+    "outline-style",
+    "outline-width",
+    "overflow",
+    "overflow-anchor",
+    "overflow-block",
+    "overflow-clip-margin",
+    "overflow-inline",
+    "overflow-wrap",
+    "overflow-x",
+    "overflow-y",
+    "overlay",
+    "override-colors",
+    "overscroll-behavior",
+    "overscroll-behavior-block",
+    "overscroll-behavior-inline",
+    "overscroll-behavior-x",
+    "overscroll-behavior-y",
+    "pad",
+    "padding",
+    "padding-block",
+    "padding-block-end",
+    "padding-block-start",
+    "padding-bottom",
+    "padding-inline",
+    "padding-inline-end",
+    "padding-inline-start",
+    "padding-left",
+    "padding-right",
+    "padding-top",
+    "page",
+    "page-break-after",
+    "page-break-before",
+    "page-break-inside",
+    "paint-order",
+    "perspective",
+    "perspective-origin",
+    "place-content",
+    "place-items",
+    "place-self",
+    "pointer-events",
+    "position",
+    "position-anchor",
+    "position-area",
+    "position-try",
+    "position-try-fallbacks",
+    "position-try-order",
+    "position-visibility",
+    "prefix",
+    "print-color-adjust",
+    "quotes",
+    "r",
+    "range",
+    "reading-flow",
+    "reading-order",
+    "resize",
+    "right",
+    "rotate",
+    "row-gap",
+    "ruby-align",
+    "ruby-position",
+    "rx",
+    "ry",
+    "scale",
+    "scroll-behavior",
+    "scroll-margin",
+    "scroll-margin-block",
+    "scroll-margin-block-end",
+    "scroll-margin-block-start",
+    "scroll-margin-bottom",
+    "scroll-margin-inline",
+    "scroll-margin-inline-end",
+    "scroll-margin-inline-start",
+    "scroll-margin-left",
+    "scroll-margin-right",
+    "scroll-margin-top",
+    "scroll-marker-group",
+    "scroll-padding",
+    "scroll-padding-block",
+    "scroll-padding-block-end",
+    "scroll-padding-block-start",
+    "scroll-padding-bottom",
+    "scroll-padding-inline",
+    "scroll-padding-inline-end",
+    "scroll-padding-inline-start",
+    "scroll-padding-left",
+    "scroll-padding-right",
+    "scroll-padding-top",
+    "scroll-snap-align",
+    "scroll-snap-stop",
+    "scroll-snap-type",
+    "scroll-timeline",
+    "scroll-timeline-axis",
+    "scroll-timeline-name",
+    "scrollbar-color",
+    "scrollbar-gutter",
+    "scrollbar-width",
+    "shape-image-threshold",
+    "shape-margin",
+    "shape-outside",
+    "shape-rendering",
+    "size",
+    "size-adjust",
+    "speak",
+    "speak-as",
+    "src",
+    "stop-color",
+    "stop-opacity",
+    "stroke",
+    "stroke-dasharray",
+    "stroke-dashoffset",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-miterlimit",
+    "stroke-opacity",
+    "stroke-width",
+    "suffix",
+    "symbols",
+    "syntax",
+    "system",
+    "tab-size",
+    "table-layout",
+    "text-align",
+    "text-align-last",
+    "text-anchor",
+    "text-autospace",
+    "text-box",
+    "text-box-edge",
+    "text-box-trim",
+    "text-combine-upright",
+    "text-decoration",
+    "text-decoration-color",
+    "text-decoration-line",
+    "text-decoration-skip",
+    "text-decoration-skip-ink",
+    "text-decoration-style",
+    "text-decoration-thickness",
+    "text-emphasis",
+    "text-emphasis-color",
+    "text-emphasis-position",
+    "text-emphasis-style",
+    "text-indent",
+    "text-justify",
+    "text-orientation",
+    "text-overflow",
+    "text-rendering",
+    "text-shadow",
+    "text-size-adjust",
+    "text-spacing-trim",
+    "text-transform",
+    "text-underline-offset",
+    "text-underline-position",
+    "text-wrap",
+    "text-wrap-mode",
+    "text-wrap-style",
+    "timeline-scope",
+    "top",
+    "touch-action",
+    "transform",
+    "transform-box",
+    "transform-origin",
+    "transform-style",
+    "transition",
+    "transition-behavior",
+    "transition-delay",
+    "transition-duration",
+    "transition-property",
+    "transition-timing-function",
+    "translate",
+    "unicode-bidi",
+    "unicode-range",
+    "user-select",
+    "vector-effect",
+    "vertical-align",
+    "view-timeline",
+    "view-timeline-axis",
+    "view-timeline-inset",
+    "view-timeline-name",
+    "view-transition-class",
+    "view-transition-name",
+    "visibility",
+    "white-space",
+    "white-space-collapse",
+    "widows",
+    "width",
+    "will-change",
+    "word-break",
+    "word-spacing",
+    "word-wrap",
+    "writing-mode",
+    "x",
+    "y",
+    "z-index",
+    "zoom",
+];
+
+// Keywords accepted by all properties.
+const GLOBAL_KEYWORDS: &[&str] = &["inherit", "initial", "revert", "revert-layer", "unset"];
+
+const OVERFLOW_KEYWORDS: &[&str] = &["auto", "clip", "hidden", "overlay", "scroll", "visible"];
+
+// Properties that only accept keywords, the values of these properties are checked if they only
+// consist of identifiers.
+const KEYWORD_PROPERTIES: &[(&str, &[&str])] = &[
+    ("backface-visibility", &["hidden", "visible"]),
+    ("border-collapse", &["collapse", "separate"]),
+    ("box-decoration-break", &["clone", "slice"]),
+    ("box-sizing", &["border-box", "content-box"]),
+    ("caption-side", &["bottom", "top"]),
+    (
+        "clear",
+        &[
+            "both",
+            "inline-end",
+            "inline-start",
+            "left",
+            "none",
+            "right",
+        ],
+    ),
+    ("content-visibility", &["auto", "hidden", "visible"]),
+    ("direction", &["ltr", "rtl"]),
+    (
+        "display",
+        &[
+            "block",
+            "contents",
+            "flex",
+            "flow",
+            "flow-root",
+            "grid",
+            "inline",
+            "inline-block",
+            "inline-flex",
+            "inline-grid",
+            "inline-table",
+            "list-item",
+            "math",
+            "none",
+            "ruby",
+            "ruby-base",
+            "ruby-base-container",
+            "ruby-text",
+            "ruby-text-container",
+            "run-in",
+            "table",
+            "table-caption",
+            "table-cell",
+            "table-column",
+            "table-column-group",
+            "table-footer-group",
+            "table-header-group",
+            "table-row",
+            "table-row-group",
+        ],
+    ),
+    ("empty-cells", &["hide", "show"]),
+    (
+        "flex-direction",
+        &["column", "column-reverse", "row", "row-reverse"],
+    ),
+    ("flex-wrap", &["nowrap", "wrap", "wrap-reverse"]),
+    (
+        "float",
+        &["inline-end", "inline-start", "left", "none", "right"],
+    ),
+    ("isolation", &["auto", "isolate"]),
+    ("list-style-position", &["inside", "outside"]),
+    (
+        "mix-blend-mode",
+        &[
+            "color",
+            "color-burn",
+            "color-dodge",
+            "darken",
+            "difference",
+            "exclusion",
+            "hard-light",
+            "hue",
+            "lighten",
+            "luminosity",
+            "multiply",
+            "normal",
+            "overlay",
+            "plus-darker",
+            "plus-lighter",
+            "saturation",
+            "screen",
+            "soft-light",
+        ],
+    ),
+    (
+        "object-fit",
+        &["contain", "cover", "fill", "none", "scale-down"],
+    ),
+    ("overflow", OVERFLOW_KEYWORDS),
+    ("overflow-wrap", &["anywhere", "break-word", "normal"]),
+    ("overflow-x", OVERFLOW_KEYWORDS),
+    ("overflow-y", OVERFLOW_KEYWORDS),
+    (
+        "pointer-events",
+        &[
+            "all",
+            "auto",
+            "fill",
+            "none",
+            "painted",
+            "stroke",
+            "visible",
+            "visiblefill",
+            "visiblepainted",
+            "visiblestroke",
+        ],
+    ),
+    (
+        "position",
+        &["absolute", "fixed", "relative", "static", "sticky"],
+    ),
+    (
+        "resize",
+        &["block", "both", "horizontal", "inline", "none", "vertical"],
+    ),
+    ("scroll-behavior", &["auto", "smooth"]),
+    ("table-layout", &["auto", "fixed"]),
+    (
+        "text-align",
+        &[
+            "center",
+            "end",
+            "justify",
+            "justify-all",
+            "left",
+            "match-parent",
+            "right",
+            "start",
+        ],
+    ),
+    (
+        "text-transform",
+        &[
+            "capitalize",
+            "full-size-kana",
+            "full-width",
+            "lowercase",
+            "math-auto",
+            "none",
+            "uppercase",
+        ],
+    ),
+    ("user-select", &["all", "auto", "contain", "none", "text"]),
+    ("visibility", &["collapse", "hidden", "visible"]),
+    (
+        "white-space",
+        &[
+            "break-spaces",
+            "collapse",
+            "normal",
+            "nowrap",
+            "pre",
+            "pre-line",
+            "pre-wrap",
+            "preserve",
+            "preserve-breaks",
+            "preserve-spaces",
+            "wrap",
+        ],
+    ),
+    (
+        "word-break",
+        &[
+            "auto-phrase",
+            "break-all",
+            "break-word",
+            "keep-all",
+            "normal",
+        ],
+    ),
+    (
+        "writing-mode",
+        &[
+            "horizontal-tb",
+            "sideways-lr",
+            "sideways-rl",
+            "vertical-lr",
+            "vertical-rl",
+        ],
+    ),
+];
+
+// Returns the edit distance between two strings.
+fn edit_distance(l: &str, r: &str) -> usize {
+    let r: Vec<char> = r.chars().collect();
+    let mut row: Vec<usize> = (0..=r.len()).collect();
+
+    for (i, lc) in l.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, rc) in r.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if lc == *rc {
+                prev
+            } else {
+                prev.min(current).min(row[j]) + 1
+            };
+            prev = current;
+        }
+    }
+
+    row[r.len()]
+}
+
+// Returns the closest candidate if it is similar enough to be a likely typo.
+fn suggest<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let max_distance = (name.len() / 3).max(1);
+
+    candidates
+        .iter()
+        .map(|m| (edit_distance(name, m), *m))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, m)| m)
+}
+
+fn with_suggestion(message: String, suggestion: Option<&str>) -> String {
+    match suggestion {
+        Some(m) => format!("{}, did you mean `{}`?", message, m),
+        None => message,
+    }
+}
+
+// Vendor prefixed names (e.g.: `-webkit-box`) and custom properties are not checked.
+fn is_unchecked_name(name: &str) -> bool {
+    name.starts_with('-')
+}
+
+/// Checks the name and the value of an attribute against known properties.
+///
+/// Custom properties, vendor prefixed properties and values that are not only made of keywords
+/// are accepted as is.
+pub fn validate_attribute(name: &CssIdent, values: &[ComponentValue]) -> Vec<ParseError> {
+    let name_str = name.to_output_string().to_ascii_lowercase();
+    if is_unchecked_name(&name_str) {
+        return Vec::new();
+    }
+
+    if PROPERTIES.binary_search(&name_str.as_str()).is_err() {
+        let message = format!("unknown property `{}`", name_str);
+        return vec![ParseError::new_spanned(
+            name,
+            with_suggestion(message, suggest(&name_str, PROPERTIES)),
+        )];
+    }
+
+    let keywords = match KEYWORD_PROPERTIES.iter().find(|(m, _)| *m == name_str) {
+        Some((_, m)) => *m,
+        None => return Vec::new(),
+    };
+
+    let idents: Option<Vec<&CssIdent>> = values
+        .iter()
+        .map(|m| match m {
+            ComponentValue::Token(PreservedToken::Ident(m)) => Some(m),
+            _ => None,
+        })
+        .collect();
+
+    let idents = match idents {
+        Some(m) => m,
+        None => return Vec::new(),
+    };
+
+    let mut errors = Vec::new();
+    for ident in idents {
+        let value = ident.to_output_string().to_ascii_lowercase();
+        if is_unchecked_name(&value)
+            || keywords.contains(&value.as_str())
+            || GLOBAL_KEYWORDS.contains(&value.as_str())
+        {
+            continue;
+        }
+
+        let message = format!("invalid value `{}` for property `{}`", value, name_str);
+        errors.push(ParseError::new_spanned(
+            ident,
+            with_suggestion(message, suggest(&value, keywords)),
+        ));
+    }
+
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_properties_sorted() {
+        assert!(PROPERTIES.windows(2).all(|m| m[0] < m[1]));
+    }
+
+    #[test]
+    fn test_suggest() {
+        assert_eq!(suggest("colr", PROPERTIES), Some("color"));
+        assert_eq!(
+            suggest("background_color", PROPERTIES),
+            Some("background-color")
+        );
+        assert_eq!(suggest("flexx", &["block", "flex", "grid"]), Some("flex"));
+        assert_eq!(suggest("something-else", PROPERTIES), None);
+    }
+}
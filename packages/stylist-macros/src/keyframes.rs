@@ -0,0 +1,8 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
+    let css_tokens = crate::css::macro_fn_impl(input, false);
+
+    quote! { ::stylist::Keyframes::new(#css_tokens) }
+}
@@ -0,0 +1,27 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse_macro_input;
+
+use super::styled_component_impl::{contextual_css_impl, HookLike, StyledComponentBaseArgs};
+
+pub fn leptos_styled_component_impl(item: HookLike) -> syn::Result<TokenStream> {
+    let use_manager = quote! { ::stylist::leptos::use_style_manager() };
+    let inner_tokens = contextual_css_impl(item, None, use_manager)?;
+
+    Ok(quote! {
+        #[::leptos::component]
+        #inner_tokens
+    })
+}
+
+pub fn macro_fn(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let item = parse_macro_input!(item as HookLike);
+    let _ = parse_macro_input!(attr as StyledComponentBaseArgs);
+
+    leptos_styled_component_impl(item)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
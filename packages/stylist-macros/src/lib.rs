@@ -13,11 +13,15 @@ mod inline;
 mod literal;
 
 mod css;
+mod css_vars;
 mod global_style;
+mod keyframes;
+mod leptos_styled_component;
 mod output;
 mod sheet;
 mod spacing_iterator;
 mod style;
+mod styled;
 mod styled_component;
 mod styled_component_impl;
 mod use_style;
@@ -40,6 +44,12 @@ pub fn global_style(input: TokenStream) -> TokenStream {
     global_style::macro_fn(input.into()).into()
 }
 
+#[proc_macro]
+#[proc_macro_error]
+pub fn keyframes(input: TokenStream) -> TokenStream {
+    keyframes::macro_fn(input.into()).into()
+}
+
 #[proc_macro]
 #[proc_macro_error]
 pub fn css(input: TokenStream) -> TokenStream {
@@ -52,6 +62,16 @@ pub fn use_style(input: TokenStream) -> TokenStream {
     use_style::macro_fn(input.into()).into()
 }
 
+#[proc_macro]
+pub fn styled(input: TokenStream) -> TokenStream {
+    styled::macro_fn(input)
+}
+
+#[proc_macro_derive(CssVars, attributes(css_vars))]
+pub fn derive_css_vars(input: TokenStream) -> TokenStream {
+    css_vars::macro_fn(input)
+}
+
 #[proc_macro_attribute]
 pub fn styled_component(attr: TokenStream, item: TokenStream) -> TokenStream {
     styled_component::macro_fn(attr, item)
@@ -61,3 +81,8 @@ pub fn styled_component(attr: TokenStream, item: TokenStream) -> TokenStream {
 pub fn styled_component_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
     styled_component_impl::macro_fn(attr, item)
 }
+
+#[proc_macro_attribute]
+pub fn leptos_styled_component(attr: TokenStream, item: TokenStream) -> TokenStream {
+    leptos_styled_component::macro_fn(attr, item)
+}
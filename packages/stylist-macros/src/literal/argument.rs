@@ -1,8 +1,10 @@
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Span, TokenStream};
 
 #[derive(Debug, Clone)]
 pub struct Argument {
+    /// The name of a named argument, the index of a positional argument or the path of an
+    /// implicitly captured argument.
     pub name: String,
-    pub name_token: Ident,
+    pub span: Span,
     pub tokens: TokenStream,
 }
@@ -1,10 +1,10 @@
 use nom::branch::alt;
 use nom::bytes::complete::{is_not, tag, take_while};
-use nom::character::complete::{alpha1, alphanumeric1};
+use nom::character::complete::{alpha1, alphanumeric1, digit1};
 use nom::combinator::{all_consuming, cut, map, opt, recognize};
 use nom::error::{context, convert_error, ErrorKind, ParseError, VerboseError};
 use nom::multi::many0;
-use nom::sequence::{delimited, preceded};
+use nom::sequence::{delimited, pair, preceded};
 use nom::IResult;
 use stylist_core::{Error, Result};
 
@@ -59,6 +59,11 @@ impl Parser {
         )
     }
 
+    /// Parse the name of an argument or a field.
+    fn name(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
+        recognize(preceded(alpha1, many0(alt((alphanumeric1, tag("_"))))))(i)
+    }
+
     /// Parse a string interpolation.
     fn interpolation(i: &str) -> IResult<&str, Fragment, VerboseError<&str>> {
         #[cfg(test)]
@@ -73,9 +78,14 @@ impl Parser {
                     tag("${"),
                     context(
                         "ArgumentName",
-                        cut(Self::trimmed(recognize(preceded(
-                            alpha1,
-                            many0(alt((alphanumeric1, tag("_")))),
+                        // A named argument, a positional argument (e.g.: `${0}`) or a path to a
+                        // field or a method of an argument (e.g.: `${theme.primary()}`).
+                        cut(Self::trimmed(recognize(pair(
+                            alt((Self::name, digit1)),
+                            many0(preceded(
+                                tag("."),
+                                pair(alt((Self::name, digit1)), opt(tag("()"))),
+                            )),
                         )))),
                     ),
                     tag("}"),
@@ -175,6 +185,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_positional_and_paths() -> Result<()> {
+        init();
+        let parsed = Parser::parse("${0} ${theme.colors.primary} ${theme.size()}")?;
+
+        let expected = vec![
+            Fragment::Interpolation("0".to_string()),
+            Fragment::Literal(" ".to_string()),
+            Fragment::Interpolation("theme.colors.primary".to_string()),
+            Fragment::Literal(" ".to_string()),
+            Fragment::Interpolation("theme.size()".to_string()),
+        ];
+
+        assert_eq!(parsed, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_invalid() {
         init();
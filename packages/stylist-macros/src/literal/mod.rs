@@ -1,4 +1,4 @@
-use proc_macro2::{TokenStream, TokenTree};
+use proc_macro2::{Spacing, TokenStream, TokenTree};
 
 use std::collections::{HashMap, HashSet};
 
@@ -18,7 +18,7 @@ use to_output_with_args::ToOutputWithArgs;
 use crate::output::{Reify, ReifyContext};
 
 pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
-    let mut tokens = input.into_iter();
+    let mut tokens = input.into_iter().peekable();
 
     let first_token = match tokens.next() {
         Some(m) => m,
@@ -45,73 +45,70 @@ pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
         }
     };
 
-    let is_equal = |t: &TokenTree| -> bool {
-        match t {
-            TokenTree::Punct(m) => m.as_char() == '=',
-            _ => false,
-        }
+    // `name = value`, but not `name == value`.
+    let is_named = |name: Option<&TokenTree>, eq: Option<&TokenTree>| -> bool {
+        matches!(
+            (name, eq),
+            (Some(TokenTree::Ident(_)), Some(TokenTree::Punct(m)))
+                if m.as_char() == '=' && m.spacing() == Spacing::Alone
+        )
     };
 
-    let mut comma_read = false;
-
-    'outer: loop {
-        if !comma_read {
-            match tokens.next() {
-                Some(m) => {
-                    if !is_comma(&m) {
-                        abort!(m, "expected ',', got: {}", m)
-                    }
-                }
-                None => break 'outer,
-            };
+    match tokens.next() {
+        Some(m) if !is_comma(&m) => abort!(m, "expected ',', got: {}", m),
+        _ => {}
+    }
+
+    let mut positional_count = 0;
+    let mut named_read = false;
+
+    loop {
+        // Collect all tokens of this argument.
+        let mut arg_tokens = Vec::new();
+        for token in tokens.by_ref() {
+            if is_comma(&token) {
+                break;
+            }
+            arg_tokens.push(token);
         }
 
-        let name_token = match tokens.next() {
-            Some(m) => m,
-            None => break 'outer,
+        let first_token = match (arg_tokens.first(), tokens.peek()) {
+            (Some(m), _) => m.clone(),
+            (None, Some(m)) => abort!(m, "expected an argument, got: {}", m),
+            (None, None) => break,
         };
 
-        let name_ident = match name_token {
-            TokenTree::Ident(ref m) => m,
-            _ => abort!(name_token, "expected ident, got: {}", name_token),
-        };
+        let arg = if is_named(arg_tokens.first(), arg_tokens.get(1)) {
+            named_read = true;
 
-        let name = name_ident.to_string();
+            Argument {
+                name: first_token.to_string(),
+                span: first_token.span(),
+                tokens: arg_tokens.into_iter().skip(2).collect(),
+            }
+        } else {
+            if named_read {
+                abort!(
+                    first_token,
+                    "positional arguments cannot follow named arguments"
+                );
+            }
+            positional_count += 1;
 
-        let mut arg = Argument {
-            name,
-            name_token: name_ident.clone(),
-            tokens: TokenStream::new(),
+            Argument {
+                name: (positional_count - 1).to_string(),
+                span: first_token.span(),
+                tokens: arg_tokens.into_iter().collect(),
+            }
         };
 
-        if !tokens.next().map(|m| is_equal(&m)).unwrap_or(false) {
-            abort!(
-                name_token,
-                "expected = at the end of this ident, only named arguments are allowed at this moment";
-                hint = format!("try: {name} = {name}", name = arg.name),
-            );
+        if arg.tokens.is_empty() {
+            abort!(first_token, "expected a value for argument {}", arg.name);
         }
 
-        'inner: loop {
-            let next_token = match tokens.next() {
-                Some(m) => m,
-                None => {
-                    if args.insert(arg.name.clone(), arg).is_some() {
-                        abort!(name_token, "duplicate named argument");
-                    }
-                    break 'outer;
-                }
-            };
-
-            if is_comma(&next_token) {
-                if args.insert(arg.name.clone(), arg).is_some() {
-                    abort!(name_token, "duplicate named argument");
-                }
-                comma_read = true;
-                break 'inner;
-            }
-
-            arg.tokens.extend(TokenStream::from(next_token));
+        let span = arg.span;
+        if args.insert(arg.name.clone(), arg).is_some() {
+            abort!(span, "duplicate named argument");
         }
     }
 
@@ -121,11 +118,7 @@ pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
 
     for (k, v) in args.iter() {
         if !args_used.contains(k) {
-            abort!(
-                v.name_token,
-                "argument {} is not used, arguments must be used",
-                k
-            );
+            abort!(v.span, "argument {} is not used, arguments must be used", k);
         }
     }
 
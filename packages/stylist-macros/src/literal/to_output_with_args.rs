@@ -1,6 +1,8 @@
 use std::collections::{HashMap, HashSet};
 
+use proc_macro2::{Span, TokenStream};
 use proc_macro_error::abort_call_site;
+use quote::quote;
 
 use stylist_core::ast::*;
 
@@ -136,13 +138,7 @@ impl ToOutputWithArgs for StringFragment {
                 }
 
                 fstring::Fragment::Interpolation(ref m) => {
-                    let arg = match args.get(m) {
-                        Some(m) => m,
-                        None => abort_call_site!("missing argument: {}", self.inner),
-                    };
-
-                    args_used.insert(arg.name.clone());
-                    fragments_out.push(arg.clone().into());
+                    fragments_out.push(resolve_argument(m, args, args_used).into());
                 }
             }
         }
@@ -212,3 +208,53 @@ impl ToOutputWithArgs for Sheet {
         OutputSheet { contents }
     }
 }
+
+// Resolves an interpolation to an argument.
+//
+// Interpolations that refer to a named argument, a field or a method of a named argument or a
+// positional argument use the argument. Other interpolations are captured from the surrounding
+// scope like `format!` does (e.g.: `${color}` or `${theme.primary}`).
+fn resolve_argument(
+    path: &str,
+    args: &HashMap<String, Argument>,
+    args_used: &mut HashSet<String>,
+) -> Argument {
+    let (root, rest) = match path.find('.') {
+        Some(m) => path.split_at(m),
+        None => (path, ""),
+    };
+
+    if let Some(arg) = args.get(root) {
+        args_used.insert(arg.name.clone());
+
+        if rest.is_empty() {
+            return arg.clone();
+        }
+
+        let arg_tokens = &arg.tokens;
+        let rest_tokens = parse_path(rest);
+
+        return Argument {
+            name: path.to_string(),
+            span: arg.span,
+            tokens: quote! { (#arg_tokens) #rest_tokens },
+        };
+    }
+
+    if root.chars().all(|m| m.is_ascii_digit()) {
+        abort_call_site!("missing positional argument: {}", root);
+    }
+
+    Argument {
+        name: path.to_string(),
+        span: Span::call_site(),
+        tokens: parse_path(path),
+    }
+}
+
+fn parse_path(path: &str) -> TokenStream {
+    match path.parse() {
+        Ok(m) => m,
+        Err(e) => abort_call_site!("invalid interpolation {}: {}", path, e),
+    }
+}
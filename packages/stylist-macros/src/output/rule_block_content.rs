@@ -1,4 +1,4 @@
-use super::{OutputAttribute, OutputBlock, OutputRule, Reify, ReifyContext};
+use super::{OutputAttribute, OutputBlock, OutputRule, OutputScopeContent, Reify, ReifyContext};
 use proc_macro2::TokenStream;
 use quote::quote;
 
@@ -9,6 +9,15 @@ pub enum OutputRuleBlockContent {
     StyleAttr(OutputAttribute),
 }
 
+impl From<OutputScopeContent> for OutputRuleBlockContent {
+    fn from(m: OutputScopeContent) -> Self {
+        match m {
+            OutputScopeContent::Rule(m) => Self::Rule(Box::new(m)),
+            OutputScopeContent::Block(m) => Self::Block(Box::new(m)),
+        }
+    }
+}
+
 impl Reify for OutputRuleBlockContent {
     fn into_token_stream(self, ctx: &mut ReifyContext) -> TokenStream {
         match self {
@@ -8,6 +8,8 @@ use crate::inline::component_value::PreservedToken;
 use crate::inline::css_ident::CssIdent;
 use crate::literal::argument::Argument;
 
+// Spans are larger if `proc-macro2/span-locations` is enabled by another crate (e.g.: leptos).
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
 pub enum OutputFragment {
     Expr(Expr),
@@ -62,7 +64,7 @@ impl OutputFragment {
             Self::Token(t) => t.to_output_string().into(),
             Self::Delimiter(kind, start) => Self::str_for_delim(kind, start).to_string().into(),
             Self::Str(s) => s.into(),
-            Self::Arg(arg) => OutputCowString::from_displayable_spanned(arg.name_token, arg.tokens),
+            Self::Arg(arg) => OutputCowString::from_displayable_spanned(arg.span, arg.tokens),
             Self::Expr(expr) => {
                 if let Expr::Lit(ExprLit {
                     lit: Lit::Str(ref litstr),
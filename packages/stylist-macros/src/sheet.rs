@@ -1,9 +1,20 @@
 use proc_macro2::{TokenStream, TokenTree};
 
+use crate::inline::CssVar;
+
 pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
+    macro_fn_with_vars(input, false).0
+}
+
+/// Creates the sheet and returns the dynamic interpolations compiled to custom properties if
+/// `#![css_vars]` is allowed and present.
+pub(crate) fn macro_fn_with_vars(
+    input: TokenStream,
+    allow_css_vars: bool,
+) -> (TokenStream, Vec<CssVar>) {
     if let Some(TokenTree::Literal(_)) = input.clone().into_iter().next() {
-        crate::literal::macro_fn(input)
+        (crate::literal::macro_fn(input), Vec::new())
     } else {
-        crate::inline::macro_fn(input)
+        crate::inline::macro_fn(input, allow_css_vars)
     }
 }
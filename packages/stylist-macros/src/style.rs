@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 use quote::quote;
 
 pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
-    let sheet_tokens = crate::sheet::macro_fn(input);
+    let css_tokens = crate::css::macro_fn_impl(input, false);
 
-    quote! { ::stylist::Style::new(#sheet_tokens) }
+    quote! { ::stylist::Style::new(#css_tokens) }
 }
@@ -0,0 +1,225 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{
+    braced, parenthesized, parse_macro_input, Attribute, Expr, Field, LitStr, Token, Visibility,
+};
+
+use super::styled_component_impl::{styled_component_impl_impl, HookLike};
+
+// Attributes of the element that are forwarded from the properties of the component.
+// Yew cannot spread arbitrary attributes onto an element, so only a fixed set is forwarded. The
+// documentation of `stylist::yew::styled` has to list the same attributes and listeners.
+const FORWARDED_ATTRS: &[&str] = &["id", "style"];
+// Listeners of the element that are forwarded from the properties of the component.
+const FORWARDED_LISTENERS: &[(&str, &str)] = &[
+    ("onclick", "MouseEvent"),
+    ("onmouseenter", "MouseEvent"),
+    ("onmouseleave", "MouseEvent"),
+    ("onfocus", "FocusEvent"),
+    ("onblur", "FocusEvent"),
+];
+
+// Elements that cannot have children. Yew panics when a dynamic tag of a void element is rendered
+// with children, so the components of these elements do not accept `children`.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+#[derive(Debug)]
+pub struct Styled {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    tag: LitStr,
+    fields: Vec<Field>,
+    css: Expr,
+}
+
+impl Parse for Styled {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let name = input.parse()?;
+
+        let mut fields = Vec::new();
+
+        let (tag, css): (LitStr, Expr) = if input.peek(Token![,]) {
+            // styled!(Name, "tag", css!(...))
+            input.parse::<Token![,]>()?;
+            let tag = input.parse()?;
+            input.parse::<Token![,]>()?;
+            let css = input.parse()?;
+
+            (tag, css)
+        } else {
+            // styled!(Name("tag") { fields } => css!(...))
+            let content;
+            parenthesized!(content in input);
+            let tag = content.parse()?;
+
+            if input.peek(syn::token::Brace) {
+                let content;
+                braced!(content in input);
+                fields.extend(Punctuated::<Field, Token![,]>::parse_terminated_with(
+                    &content,
+                    Field::parse_named,
+                )?);
+            }
+
+            input.parse::<Token![=>]>()?;
+            let css = input.parse()?;
+
+            (tag, css)
+        };
+
+        // Allows a trailing comma or semicolon.
+        if !input.is_empty() {
+            if input.peek(Token![;]) {
+                input.parse::<Token![;]>()?;
+            } else {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens after the style of the component"));
+        }
+
+        if tag.value().is_empty() {
+            return Err(syn::Error::new_spanned(
+                tag,
+                "expected the name of an HTML element",
+            ));
+        }
+
+        Ok(Self {
+            attrs,
+            vis,
+            name,
+            tag,
+            fields,
+            css,
+        })
+    }
+}
+
+// Converts the name of a component to the name of its function (e.g.: `PrimaryButton` ->
+// `primary_button`).
+fn to_snake_case(ident: &Ident) -> Ident {
+    let mut result = String::new();
+    for (index, c) in ident.to_string().chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if index > 0 {
+                result.push('_');
+            }
+            result.push(c.to_ascii_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+
+    format_ident!("{}", result, span = ident.span())
+}
+
+fn styled_impl(input: Styled) -> syn::Result<TokenStream> {
+    let Styled {
+        attrs,
+        vis,
+        name,
+        tag,
+        fields,
+        css,
+    } = input;
+
+    let props_ident = format_ident!("{}Props", name);
+    let fn_ident = to_snake_case(&name);
+    let props_doc = format!("The properties of [`{}`].", name);
+
+    let attr_idents: Vec<_> = FORWARDED_ATTRS
+        .iter()
+        .map(|m| format_ident!("{}", m))
+        .collect();
+    // The `style` attribute is rendered with the custom properties of the style.
+    let element_attr_idents: Vec<_> = attr_idents.iter().filter(|m| *m != "style").collect();
+    let listener_idents: Vec<_> = FORWARDED_LISTENERS
+        .iter()
+        .map(|(m, _)| format_ident!("{}", m))
+        .collect();
+    let listener_events: Vec<_> = FORWARDED_LISTENERS
+        .iter()
+        .map(|(_, m)| format_ident!("{}", m))
+        .collect();
+
+    let is_void = VOID_ELEMENTS
+        .iter()
+        .any(|m| m.eq_ignore_ascii_case(&tag.value()));
+
+    let element_attrs = quote! {
+        class={::yew::classes!(style, ::std::clone::Clone::clone(&props.class))}
+        style={style_attr}
+        #(#element_attr_idents={::std::clone::Clone::clone(&props.#element_attr_idents)})*
+        #(#listener_idents={::std::clone::Clone::clone(&props.#listener_idents)})*
+    };
+    let element = if is_void {
+        quote! { <@{#tag} #element_attrs /> }
+    } else {
+        quote! {
+            <@{#tag} #element_attrs>
+                {::std::clone::Clone::clone(&props.children)}
+            </@>
+        }
+    };
+    let children_field = (!is_void).then(|| {
+        quote! {
+            #[prop_or_default]
+            pub children: ::yew::Children,
+        }
+    });
+
+    let func = syn::parse2(quote! {
+        #(#attrs)*
+        #vis fn #fn_ident(props: &#props_ident) -> ::yew::Html {
+            let (style, style_attr) = ::stylist::yew::use_styled_source(
+                #css,
+                ::std::clone::Clone::clone(&props.style),
+            );
+
+            ::yew::html! { #element }
+        }
+    })?;
+
+    let inner_tokens = styled_component_impl_impl(HookLike { func }, Some(&name))?;
+
+    Ok(quote! {
+        #[doc = #props_doc]
+        #[derive(::yew::Properties, ::std::cmp::PartialEq)]
+        #vis struct #props_ident {
+            #(#fields,)*
+            #children_field
+            #[prop_or_default]
+            pub class: ::yew::Classes,
+            #(
+                #[prop_or_default]
+                pub #attr_idents: ::std::option::Option<::yew::AttrValue>,
+            )*
+            #(
+                #[prop_or_default]
+                pub #listener_idents: ::std::option::Option<::yew::Callback<::yew::events::#listener_events>>,
+            )*
+        }
+
+        #[::yew::functional::function_component(#name)]
+        #inner_tokens
+    })
+}
+
+pub fn macro_fn(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as Styled);
+
+    styled_impl(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -47,7 +47,7 @@ pub fn styled_component_impl(
     let StyledComponentName { component_name } = name;
     let StyledComponent { func } = component;
 
-    let inner_tokens = styled_component_impl_impl(HookLike { func })?;
+    let inner_tokens = styled_component_impl_impl(HookLike { func }, Some(&component_name))?;
 
     Ok(quote! {
         #[::yew::functional::function_component(#component_name)]
@@ -1,6 +1,7 @@
 // This file is borrowed from yew-macro/src/function_component.rs
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
 use syn::{parse_macro_input, Ident, Item, ItemFn};
 
@@ -33,7 +34,25 @@ impl Parse for StyledComponentBaseArgs {
     }
 }
 
-pub fn styled_component_impl_impl(item: HookLike) -> syn::Result<TokenStream> {
+// The name is attached to the styles created by the contextual `css!` macro.
+// If no name is provided, the name of the function is used.
+pub fn styled_component_impl_impl(
+    item: HookLike,
+    name: Option<&Ident>,
+) -> syn::Result<TokenStream> {
+    let use_manager = quote! {
+        ::stylist::yew::use_style_manager()
+    };
+
+    contextual_css_impl(item, name, use_manager)
+}
+
+// Injects a `css!` macro that creates styles with the manager returned by `use_manager`.
+pub fn contextual_css_impl(
+    item: HookLike,
+    name: Option<&Ident>,
+    use_manager: TokenStream,
+) -> syn::Result<TokenStream> {
     let HookLike { func } = item;
 
     let ItemFn {
@@ -43,15 +62,19 @@ pub fn styled_component_impl_impl(item: HookLike) -> syn::Result<TokenStream> {
         block,
     } = func;
 
+    let name = name.unwrap_or(&sig.ident).unraw().to_string();
+
     let mgr_ident = Ident::new("__stylist_style_manager__", Span::mixed_site());
     let macro_tokens = quote! {
         #[allow(unused_macros)]
         macro_rules! css {
             ($( $args:tt )*) => {
-                ::stylist::css!($($args)*).with_manager({
-                    #[allow(clippy::redundant_clone)]
-                    #mgr_ident.clone()
-                })
+                ::stylist::css!($($args)*)
+                    .with_manager({
+                        #[allow(clippy::redundant_clone)]
+                        #mgr_ident.clone()
+                    })
+                    .with_name(#name)
             }
         }
     };
@@ -59,7 +82,7 @@ pub fn styled_component_impl_impl(item: HookLike) -> syn::Result<TokenStream> {
     let quoted = quote! {
         #(#attrs)*
         #vis #sig {
-            let #mgr_ident = ::yew::functional::use_context::<::stylist::manager::StyleManager>().unwrap_or_default();
+            let #mgr_ident = #use_manager;
             #macro_tokens
 
             #block
@@ -76,7 +99,7 @@ pub fn macro_fn(
     let item = parse_macro_input!(item as HookLike);
     let _ = parse_macro_input!(attr as StyledComponentBaseArgs);
 
-    styled_component_impl_impl(item)
+    styled_component_impl_impl(item, None)
         .unwrap_or_else(|err| err.to_compile_error())
         .into()
 }
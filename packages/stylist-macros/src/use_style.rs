@@ -2,5 +2,7 @@ use proc_macro2::TokenStream;
 use quote::quote;
 
 pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
-    quote! { ::stylist::yew::use_style(::stylist::css!(#input)) }
+    let css_tokens = crate::css::macro_fn_impl(input, false);
+
+    quote! { ::stylist::yew::use_style(#css_tokens) }
 }
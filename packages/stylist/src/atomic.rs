@@ -0,0 +1,300 @@
+//! Decomposition of stylesheets into atomic styles.
+//!
+//! In atomic mode, every style attribute becomes a separate style together with the at-rules and
+//! the selectors it is nested in. Styles that contain the same declaration share the same class.
+//!
+//! Atoms nested in at-rules are mounted after all other atoms, so a conditional declaration
+//! always takes precedence over a plain declaration of the same property regardless of the order
+//! atoms are created in.
+
+use std::borrow::Cow;
+
+use crate::ast::{
+    Block, Rule, RuleBlockContent, ScopeContent, Selector, Sheet, StringFragment, StyleAttribute,
+    ToStyleStr,
+};
+
+// At-rules that only apply their content conditionally and can be split into atoms.
+const CONDITIONAL_RULES: &[&str] = &["@media", "@supports", "@container", "@layer"];
+
+/// An attribute with the at-rules and the selectors it is nested in.
+#[derive(Debug, Clone)]
+struct Atom {
+    rules: Vec<Cow<'static, [StringFragment]>>,
+    selectors: Cow<'static, [Selector]>,
+    attr: StyleAttribute,
+}
+
+impl Atom {
+    fn is_same_property(&self, other: &Atom) -> bool {
+        self.rules == other.rules
+            && self.selectors == other.selectors
+            && self.attr.key == other.attr.key
+    }
+
+    fn into_scope(self) -> ScopeContent {
+        let Self {
+            rules,
+            selectors,
+            attr,
+        } = self;
+
+        let block = Block {
+            condition: selectors,
+            content: vec![attr.into()].into(),
+        };
+
+        // Wraps the block with rules from the innermost one.
+        let mut rules = rules.into_iter().rev();
+        let mut rule = match rules.next() {
+            Some(condition) => Rule {
+                condition,
+                content: vec![RuleBlockContent::Block(block.into())].into(),
+            },
+            None => return ScopeContent::Block(block),
+        };
+
+        for condition in rules {
+            rule = Rule {
+                condition,
+                content: vec![RuleBlockContent::Rule(rule.into())].into(),
+            };
+        }
+
+        ScopeContent::Rule(rule)
+    }
+}
+
+fn is_important(attr: &StyleAttribute) -> bool {
+    let mut s = String::new();
+    for frag in attr.value.iter() {
+        s.push_str(&frag.to_style_str(None));
+    }
+
+    let s: String = s.chars().filter(|m| !m.is_whitespace()).collect();
+    s.to_ascii_lowercase().ends_with("!important")
+}
+
+fn is_conditional(condition: &[StringFragment]) -> bool {
+    let mut s = String::new();
+    for frag in condition.iter() {
+        s.push_str(&frag.to_style_str(None));
+    }
+
+    CONDITIONAL_RULES.iter().any(|m| {
+        s.strip_prefix(m)
+            .map(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+            .unwrap_or(false)
+    })
+}
+
+// Collects the atoms of a rule, returns `false` if the rule can not be decomposed.
+fn collect_rule(
+    rules: &mut Vec<Cow<'static, [StringFragment]>>,
+    rule: &Rule,
+    atoms: &mut Vec<Atom>,
+) -> bool {
+    if !is_conditional(&rule.condition) {
+        return false;
+    }
+
+    rules.push(rule.condition.clone());
+
+    let mut result = true;
+    for content in rule.content.iter() {
+        result = match content {
+            RuleBlockContent::StyleAttr(ref m) => {
+                atoms.push(Atom {
+                    rules: rules.clone(),
+                    selectors: Cow::Borrowed(&[]),
+                    attr: m.clone(),
+                });
+                true
+            }
+            RuleBlockContent::Block(ref m) => collect_block(rules, m, atoms),
+            RuleBlockContent::Rule(ref m) => collect_rule(rules, m, atoms),
+        };
+
+        if !result {
+            break;
+        }
+    }
+
+    rules.pop();
+
+    result
+}
+
+// Collects the atoms of a block, returns `false` if the block can not be decomposed.
+fn collect_block(
+    rules: &[Cow<'static, [StringFragment]>],
+    block: &Block,
+    atoms: &mut Vec<Atom>,
+) -> bool {
+    for content in block.content.iter() {
+        match content {
+            RuleBlockContent::StyleAttr(ref m) => atoms.push(Atom {
+                rules: rules.to_vec(),
+                selectors: block.condition.clone(),
+                attr: m.clone(),
+            }),
+            // Nested blocks and rules in blocks are kept as they are.
+            RuleBlockContent::Block(_) | RuleBlockContent::Rule(_) => return false,
+        }
+    }
+
+    true
+}
+
+/// A stylesheet split into atoms.
+#[derive(Debug, Default)]
+pub(crate) struct Decomposed {
+    /// Atoms that are not nested in an at-rule.
+    pub plain: Vec<Sheet>,
+    /// Atoms that are nested in at-rules.
+    pub conditional: Vec<Sheet>,
+    /// Content that can not be split.
+    pub rest: Sheet,
+}
+
+/// Splits a stylesheet into a sheet for each declaration and a sheet of content that can not be
+/// split.
+///
+/// A declaration that is overridden by another declaration of the same property in the same
+/// context is dropped.
+pub(crate) fn decompose(sheet: &Sheet) -> Decomposed {
+    let mut atoms: Vec<Atom> = Vec::new();
+    let mut rest = Vec::new();
+
+    for scope in sheet.iter() {
+        let mut scope_atoms = Vec::new();
+
+        let decomposed = match scope {
+            ScopeContent::Block(ref m) => collect_block(&[], m, &mut scope_atoms),
+            ScopeContent::Rule(ref m) => collect_rule(&mut Vec::new(), m, &mut scope_atoms),
+        };
+
+        if decomposed {
+            for atom in scope_atoms {
+                match atoms.iter().position(|m| m.is_same_property(&atom)) {
+                    // An important declaration is only overridden by another important
+                    // declaration.
+                    Some(i) if is_important(&atoms[i].attr) && !is_important(&atom.attr) => {}
+                    Some(i) => {
+                        atoms.remove(i);
+                        atoms.push(atom);
+                    }
+                    None => atoms.push(atom),
+                }
+            }
+        } else {
+            rest.push(scope.clone());
+        }
+    }
+
+    let mut decomposed = Decomposed {
+        rest: Sheet::from(rest),
+        ..Default::default()
+    };
+
+    for atom in atoms {
+        let bucket = if atom.rules.is_empty() {
+            &mut decomposed.plain
+        } else {
+            &mut decomposed.conditional
+        };
+
+        bucket.push(Sheet::from(vec![atom.into_scope()]));
+    }
+
+    decomposed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decompose_str(s: &str) -> (Vec<String>, String) {
+        let sheet: Sheet = s.parse().expect("Failed to parse style.");
+        let decomposed = decompose(&sheet);
+
+        (
+            decomposed
+                .plain
+                .iter()
+                .chain(decomposed.conditional.iter())
+                .map(|m| m.to_style_str(Some("a")))
+                .collect(),
+            decomposed.rest.to_style_str(Some("a")),
+        )
+    }
+
+    #[test]
+    fn test_decompose() {
+        let (atoms, rest) = decompose_str(
+            r#"
+                display: flex;
+                color: red;
+                &:hover {
+                    color: blue;
+                }
+                @media screen and (max-width: 600px) {
+                    display: block;
+                    .nested {
+                        gap: 8px;
+                    }
+                }
+                color: green;
+            "#,
+        );
+
+        assert_eq!(
+            atoms,
+            vec![
+                ".a {\n    display: flex;\n}\n",
+                ".a:hover {\n    color: blue;\n}\n",
+                ".a {\n    color: green;\n}\n",
+                "@media screen and (max-width: 600px) {\n    .a {\n        display: block;\n    }\n}\n",
+                "@media screen and (max-width: 600px) {\n    .a .nested {\n        gap: 8px;\n    }\n}\n",
+            ]
+        );
+        assert_eq!(rest, "");
+    }
+
+    #[test]
+    fn test_important() {
+        let (atoms, _) = decompose_str(
+            r#"
+                color: red !important;
+                color: blue;
+                gap: 4px !important;
+                gap: 8px ! IMPORTANT;
+            "#,
+        );
+
+        assert_eq!(
+            atoms,
+            vec![
+                ".a {\n    color: red !important;\n}\n",
+                ".a {\n    gap: 8px ! IMPORTANT;\n}\n",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rest() {
+        let (atoms, rest) = decompose_str(
+            r#"
+                color: red;
+                @keyframes move {
+                    from {
+                        top: 0px;
+                    }
+                }
+            "#,
+        );
+
+        assert_eq!(atoms, vec![".a {\n    color: red;\n}\n"]);
+        assert!(rest.starts_with("@keyframes move {"));
+    }
+}
@@ -0,0 +1,290 @@
+use std::rc::Weak;
+
+use crate::Result;
+
+/// The way how a [`StyleManager`](crate::manager::StyleManager) inserts styles into the DOM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MountMode {
+    /// Styles are mounted and unmounted as soon as they are created and dropped.
+    #[default]
+    Immediate,
+    /// Mounts and unmounts are queued until
+    /// [`StyleManager::flush`](crate::manager::StyleManager::flush) is called.
+    Manual,
+    /// Mounts and unmounts are queued and flushed in a microtask.
+    ///
+    /// On non-wasm targets, styles are mounted and unmounted immediately.
+    Microtask,
+    /// Mounts and unmounts are queued and flushed before the next repaint of the browser.
+    ///
+    /// On non-wasm targets, styles are mounted and unmounted immediately.
+    AnimationFrame,
+    /// Mounts and unmounts are queued and flushed after a yew component that uses
+    /// [`use_style_manager`](crate::yew::use_style_manager) (e.g.: a `styled_component`) has
+    /// rendered, before the browser paints.
+    ///
+    /// Without the yew integration, the queue has to be flushed manually like with
+    /// [`MountMode::Manual`]. On non-wasm targets, styles are mounted and unmounted immediately.
+    Render,
+}
+
+impl MountMode {
+    /// Returns `true` if mounts and unmounts are queued.
+    ///
+    /// Only [`MountMode::Manual`] queues on non-wasm targets as the other modes are never flushed
+    /// there.
+    pub fn is_batched(&self) -> bool {
+        match self {
+            Self::Immediate => false,
+            Self::Manual => true,
+            Self::Microtask | Self::AnimationFrame | Self::Render => cfg!(target_arch = "wasm32"),
+        }
+    }
+}
+
+/// A queued DOM operation.
+///
+/// `M` is the content of a style and `E` the handle of a mounted element.
+#[derive(Debug)]
+pub(crate) enum PendingOp<M, E> {
+    /// Mounts a style.
+    ///
+    /// The queue only keeps a weak reference so a style that is dropped before the queue is
+    /// flushed is never mounted.
+    Mount(Weak<M>),
+    /// Removes elements of a style that has been unmounted.
+    Unmount(Vec<E>),
+}
+
+/// The target pending operations are applied to.
+pub(crate) trait Backend<M, E> {
+    /// Mounts a style.
+    fn mount(&mut self, content: &M) -> Result<()>;
+
+    /// Removes the elements of an unmounted style.
+    fn unmount(&mut self, elements: Vec<E>) -> Result<()>;
+}
+
+/// A queue of DOM operations.
+#[derive(Debug)]
+pub(crate) struct PendingOps<M, E> {
+    ops: Vec<PendingOp<M, E>>,
+    scheduled: bool,
+}
+
+impl<M, E> Default for PendingOps<M, E> {
+    fn default() -> Self {
+        Self {
+            ops: Vec::new(),
+            scheduled: false,
+        }
+    }
+}
+
+impl<M, E> PendingOps<M, E> {
+    pub fn push_mount(&mut self, content: Weak<M>) {
+        self.ops.push(PendingOp::Mount(content));
+    }
+
+    pub fn push_unmount(&mut self, elements: Vec<E>) {
+        // Styles that have never been mounted do not need to be removed.
+        if !elements.is_empty() {
+            self.ops.push(PendingOp::Unmount(elements));
+        }
+    }
+
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Marks the queue as scheduled to be flushed.
+    ///
+    /// Returns `true` if a flush has not been scheduled already.
+    #[cfg(any(test, target_arch = "wasm32"))]
+    pub fn schedule(&mut self) -> bool {
+        !std::mem::replace(&mut self.scheduled, true)
+    }
+
+    /// Returns `true` if a mount of the style is queued.
+    pub fn is_mount_pending(&self, content: &M) -> bool {
+        self.ops
+            .iter()
+            .any(|m| matches!(m, PendingOp::Mount(m) if std::ptr::eq(m.as_ptr(), content)))
+    }
+
+    /// Removes all queued mounts, queued unmounts are kept.
+    pub fn cancel_mounts(&mut self) {
+        self.ops.retain(|m| matches!(m, PendingOp::Unmount(_)));
+    }
+
+    /// Takes all pending operations.
+    pub fn take(&mut self) -> Vec<PendingOp<M, E>> {
+        self.scheduled = false;
+        std::mem::take(&mut self.ops)
+    }
+}
+
+/// Applies operations in the order they are queued.
+///
+/// All operations are applied even if some of them fail, the first error is returned.
+pub(crate) fn apply<M, E, B>(ops: Vec<PendingOp<M, E>>, backend: &mut B) -> Result<()>
+where
+    B: Backend<M, E>,
+{
+    let mut result = Ok(());
+
+    for op in ops {
+        let op_result = match op {
+            PendingOp::Mount(m) => match m.upgrade() {
+                Some(m) => backend.mount(&m),
+                None => Ok(()),
+            },
+            PendingOp::Unmount(m) => backend.unmount(m),
+        };
+
+        if result.is_ok() {
+            result = op_result;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::Error;
+
+    #[derive(Debug, Default)]
+    struct MockBackend {
+        mounted: Vec<String>,
+        removed: Vec<u32>,
+    }
+
+    impl Backend<String, u32> for MockBackend {
+        fn mount(&mut self, content: &String) -> Result<()> {
+            if content == "fail" {
+                return Err(Error::Web(None));
+            }
+
+            self.mounted.push(content.clone());
+            Ok(())
+        }
+
+        fn unmount(&mut self, elements: Vec<u32>) -> Result<()> {
+            self.removed.extend(elements);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_apply_in_order() {
+        let a = Rc::new("a".to_string());
+        let b = Rc::new("b".to_string());
+
+        let mut queue = PendingOps::default();
+        queue.push_mount(Rc::downgrade(&a));
+        queue.push_unmount(vec![1, 2]);
+        queue.push_unmount(vec![]);
+        queue.push_mount(Rc::downgrade(&b));
+        assert_eq!(queue.len(), 3);
+
+        let mut backend = MockBackend::default();
+        apply(queue.take(), &mut backend).expect("Failed to apply operations.");
+
+        assert_eq!(queue.len(), 0);
+        assert_eq!(backend.mounted, vec!["a", "b"]);
+        assert_eq!(backend.removed, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_dropped_before_flush() {
+        let a = Rc::new("a".to_string());
+        let b = Rc::new("b".to_string());
+
+        let mut queue = PendingOps::default();
+        queue.push_mount(Rc::downgrade(&a));
+        queue.push_mount(Rc::downgrade(&b));
+        drop(a);
+
+        let mut backend = MockBackend::default();
+        apply(queue.take(), &mut backend).expect("Failed to apply operations.");
+
+        assert_eq!(backend.mounted, vec!["b"]);
+    }
+
+    #[test]
+    fn test_error_does_not_stop_flush() {
+        let a = Rc::new("fail".to_string());
+        let b = Rc::new("b".to_string());
+
+        let mut queue = PendingOps::default();
+        queue.push_mount(Rc::downgrade(&a));
+        queue.push_mount(Rc::downgrade(&b));
+
+        let mut backend = MockBackend::default();
+        assert!(apply(queue.take(), &mut backend).is_err());
+        assert_eq!(backend.mounted, vec!["b"]);
+    }
+
+    #[test]
+    fn test_cancel_mounts() {
+        let a = Rc::new("a".to_string());
+
+        let mut queue = PendingOps::default();
+        queue.push_mount(Rc::downgrade(&a));
+        queue.push_unmount(vec![1]);
+        queue.cancel_mounts();
+        assert_eq!(queue.len(), 1);
+
+        let mut backend = MockBackend::default();
+        apply(queue.take(), &mut backend).expect("Failed to apply operations.");
+
+        assert!(backend.mounted.is_empty());
+        assert_eq!(backend.removed, vec![1]);
+    }
+
+    #[test]
+    fn test_is_mount_pending() {
+        let a = Rc::new("a".to_string());
+        let b = Rc::new("a".to_string());
+
+        let mut queue = PendingOps::<String, u32>::default();
+        queue.push_mount(Rc::downgrade(&a));
+        assert!(queue.is_mount_pending(&a));
+        // Styles are compared by identity.
+        assert!(!queue.is_mount_pending(&b));
+
+        queue.take();
+        assert!(!queue.is_mount_pending(&a));
+    }
+
+    #[test]
+    fn test_is_batched() {
+        assert!(!MountMode::Immediate.is_batched());
+        assert!(MountMode::Manual.is_batched());
+
+        // Modes that are flushed by the browser are not batched on non-wasm targets.
+        for mode in [
+            MountMode::Microtask,
+            MountMode::AnimationFrame,
+            MountMode::Render,
+        ] {
+            assert_eq!(mode.is_batched(), cfg!(target_arch = "wasm32"));
+        }
+    }
+
+    #[test]
+    fn test_schedule() {
+        let mut queue = PendingOps::<String, u32>::default();
+
+        assert!(queue.schedule());
+        assert!(!queue.schedule());
+
+        queue.take();
+        assert!(queue.schedule());
+    }
+}
@@ -0,0 +1,233 @@
+use std::cell::Cell;
+use std::fmt;
+
+use crate::registry::{StyleKind, StyleRegistry};
+use crate::utils::{get_entropy, to_base36};
+
+/// Information available to a [`ClassNameGenerator`] when it generates a name for a style.
+#[derive(Debug)]
+pub struct ClassNameContext<'a> {
+    pub(crate) prefix: &'a str,
+    pub(crate) name: Option<&'a str>,
+    pub(crate) kind: StyleKind,
+    pub(crate) registry: &'a StyleRegistry,
+}
+
+impl ClassNameContext<'_> {
+    /// The prefix of the style.
+    ///
+    /// For global styles, this is the prefix of the manager followed by `-global`. For keyframes,
+    /// this is the prefix of the manager followed by `-keyframes`.
+    pub fn prefix(&self) -> &str {
+        self.prefix
+    }
+
+    /// The name attached to the [`StyleSource`](crate::StyleSource), if any.
+    ///
+    /// [`css!`](crate::css) attaches the path of the calling module and
+    /// [`styled_component`](crate::yew::styled_component) attaches the name of the component.
+    pub fn name(&self) -> Option<&str> {
+        self.name
+    }
+
+    /// Returns `true` if the name is generated for a [`GlobalStyle`](crate::GlobalStyle).
+    pub fn is_global(&self) -> bool {
+        self.kind == StyleKind::Global
+    }
+
+    /// Returns `true` if the name is generated for a [`Keyframes`](crate::Keyframes).
+    ///
+    /// The generated name is used as the animation name.
+    pub fn is_keyframes(&self) -> bool {
+        self.kind == StyleKind::Keyframes
+    }
+
+    /// Returns `true` if a style with the provided id is already registered with the manager.
+    ///
+    /// Generators can use this to check for collisions.
+    pub fn is_registered(&self, id: &str) -> bool {
+        self.registry.get_by_id(id).is_some()
+    }
+}
+
+/// A trait to customise the class names generated for styles.
+///
+/// The generated name is used as the [`StyleId`](crate::manager::StyleId) of a style and as the
+/// class name of scoped styles, hence it has to be a valid CSS identifier that is unique among the
+/// styles registered with the same manager.
+///
+/// Use [`StyleManagerBuilder::class_name_generator`](crate::manager::StyleManagerBuilder::class_name_generator)
+/// to set the generator of a manager.
+pub trait ClassNameGenerator: fmt::Debug {
+    /// Generates a name for a style.
+    fn generate(&self, ctx: &ClassNameContext<'_>) -> String;
+}
+
+/// The default class name generator.
+///
+/// This generator creates class names in the form of `{prefix}-{entropy}`. The entropy is random
+/// if feature `random` is enabled and counter-based otherwise.
+#[derive(Debug, Default, Clone)]
+pub struct DefaultClassNameGenerator;
+
+impl ClassNameGenerator for DefaultClassNameGenerator {
+    fn generate(&self, ctx: &ClassNameContext<'_>) -> String {
+        format!("{}-{}", ctx.prefix(), get_entropy())
+    }
+}
+
+/// A class name generator that creates short class names.
+///
+/// Class names are generated in the form of `{prefix}-{counter}`, where the counter is encoded in
+/// base 36. This is suitable for production builds.
+#[derive(Debug, Default)]
+pub struct CounterClassNameGenerator {
+    ctr: Cell<u64>,
+}
+
+impl CounterClassNameGenerator {
+    /// Creates a new generator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ClassNameGenerator for CounterClassNameGenerator {
+    fn generate(&self, ctx: &ClassNameContext<'_>) -> String {
+        loop {
+            let ctr = self.ctr.get();
+            self.ctr.set(ctr + 1);
+
+            let id = format!("{}-{}", ctx.prefix(), to_base36(ctr));
+            if !ctx.is_registered(&id) {
+                return id;
+            }
+        }
+    }
+}
+
+/// A class name generator that creates readable class names.
+///
+/// Class names are generated in the form of `{name}-{counter}` where `name` is the name attached to
+/// the [`StyleSource`](crate::StyleSource) (e.g.: `Button-3` for a style created in a
+/// [`styled_component`](crate::yew::styled_component) named `Button`). If no name is attached or
+/// the style is global, the prefix is used instead.
+///
+/// This is useful during development to find out where a style is created.
+#[derive(Debug, Default)]
+pub struct DebugClassNameGenerator {
+    ctr: Cell<u64>,
+}
+
+impl DebugClassNameGenerator {
+    /// Creates a new generator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Turns the attached name into a valid CSS identifier.
+    //
+    // Only the last segment of a module path is used.
+    fn sanitize_name(name: &str) -> Option<String> {
+        let name = name.rsplit("::").next().unwrap_or(name);
+
+        let mut sanitized: String = name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+
+        if sanitized.is_empty() {
+            return None;
+        }
+
+        // An identifier can not start with a digit or a hyphen followed by a digit.
+        let digit_start = sanitized
+            .strip_prefix('-')
+            .unwrap_or(&sanitized)
+            .starts_with(|c: char| c.is_ascii_digit());
+        if digit_start || sanitized == "-" {
+            sanitized.insert(0, '_');
+        }
+
+        Some(sanitized)
+    }
+}
+
+impl ClassNameGenerator for DebugClassNameGenerator {
+    fn generate(&self, ctx: &ClassNameContext<'_>) -> String {
+        let name = ctx
+            .name()
+            .filter(|_| !ctx.is_global())
+            .and_then(Self::sanitize_name)
+            .unwrap_or_else(|| ctx.prefix().to_string());
+
+        loop {
+            let ctr = self.ctr.get();
+            self.ctr.set(ctr + 1);
+
+            let id = format!("{}-{}", name, ctr);
+            if !ctx.is_registered(&id) {
+                return id;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generate<G: ClassNameGenerator>(gen: &G, name: Option<&str>, is_global: bool) -> String {
+        let registry = StyleRegistry::default();
+        let ctx = ClassNameContext {
+            prefix: "stylist",
+            name,
+            kind: if is_global {
+                StyleKind::Global
+            } else {
+                StyleKind::Scoped
+            },
+            registry: &registry,
+        };
+
+        gen.generate(&ctx)
+    }
+
+    #[test]
+    fn test_counter_generator() {
+        let gen = CounterClassNameGenerator::new();
+
+        assert_eq!(generate(&gen, None, false), "stylist-0");
+        for _ in 0..34 {
+            generate(&gen, None, false);
+        }
+        assert_eq!(generate(&gen, None, false), "stylist-z");
+        assert_eq!(generate(&gen, None, false), "stylist-10");
+    }
+
+    #[test]
+    fn test_debug_generator() {
+        let gen = DebugClassNameGenerator::new();
+
+        assert_eq!(
+            generate(&gen, Some("Button-primary"), false),
+            "Button-primary-0"
+        );
+        assert_eq!(
+            generate(&gen, Some("my_app::components::button"), false),
+            "button-1"
+        );
+        assert_eq!(generate(&gen, Some("3d"), false), "_3d-2");
+        assert_eq!(generate(&gen, Some("-1"), false), "_-1-3");
+        assert_eq!(generate(&gen, Some("<"), false), "_--4");
+        assert_eq!(generate(&gen, Some("--x"), false), "--x-5");
+        assert_eq!(generate(&gen, None, false), "stylist-6");
+        assert_eq!(generate(&gen, Some("Button"), true), "stylist-7");
+    }
+}
@@ -0,0 +1,121 @@
+/// Reference-counted containers of a [`StyleManager`](crate::manager::StyleManager).
+///
+/// `N` is the handle of a container.
+#[derive(Debug)]
+pub(crate) struct Containers<N> {
+    // Each container is stored with the number of times it has been added.
+    entries: Vec<(N, usize)>,
+}
+
+impl<N> Default for Containers<N> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<N> Containers<N>
+where
+    N: PartialEq + Clone,
+{
+    /// Adds a container.
+    ///
+    /// Returns `true` if the container has not been present, in which case styles have to be
+    /// mounted into it.
+    pub fn add(&mut self, container: N) -> bool {
+        if let Some((_, count)) = self.entries.iter_mut().find(|(m, _)| m == &container) {
+            *count += 1;
+            return false;
+        }
+
+        self.entries.push((container, 1));
+        true
+    }
+
+    /// Removes a container.
+    ///
+    /// Returns `true` if the container has been removed as many times as it has been added, in
+    /// which case styles have to be unmounted from it. Removing a container that is not present
+    /// returns `false`.
+    pub fn remove(&mut self, container: &N) -> bool {
+        let pos = match self.entries.iter().position(|(m, _)| m == container) {
+            Some(m) => m,
+            None => return false,
+        };
+
+        self.entries[pos].1 -= 1;
+        if self.entries[pos].1 > 0 {
+            return false;
+        }
+
+        self.entries.remove(pos);
+        true
+    }
+
+    /// Removes a container regardless of the number of times it has been added.
+    #[cfg(any(test, target_arch = "wasm32"))]
+    pub fn remove_all(&mut self, container: &N) {
+        self.entries.retain(|(m, _)| m != container);
+    }
+
+    /// Returns all containers in the order they were added.
+    pub fn to_vec(&self) -> Vec<N> {
+        self.entries.iter().map(|(m, _)| m.clone()).collect()
+    }
+}
+
+impl<N> FromIterator<N> for Containers<N>
+where
+    N: PartialEq + Clone,
+{
+    fn from_iter<I: IntoIterator<Item = N>>(iter: I) -> Self {
+        let mut containers = Self::default();
+        for m in iter {
+            containers.add(m);
+        }
+
+        containers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_remove() {
+        let mut containers: Containers<&str> = Some("head").into_iter().collect();
+
+        assert!(containers.add("shadow"));
+        assert!(!containers.add("shadow"));
+        assert!(!containers.add("head"));
+        assert_eq!(containers.to_vec(), vec!["head", "shadow"]);
+
+        assert!(!containers.remove(&"shadow"));
+        assert!(containers.remove(&"shadow"));
+        assert_eq!(containers.to_vec(), vec!["head"]);
+
+        // Removing a container that is not present does nothing.
+        assert!(!containers.remove(&"shadow"));
+
+        assert!(!containers.remove(&"head"));
+        assert!(containers.remove(&"head"));
+        assert!(containers.to_vec().is_empty());
+
+        // A container removed as many times as it has been added can be added again.
+        assert!(containers.add("head"));
+    }
+
+    #[test]
+    fn test_remove_all() {
+        let mut containers = Containers::default();
+
+        assert!(containers.add("shadow"));
+        assert!(!containers.add("shadow"));
+
+        containers.remove_all(&"shadow");
+        assert!(containers.to_vec().is_empty());
+        assert!(containers.add("shadow"));
+    }
+}
@@ -0,0 +1,75 @@
+use std::borrow::Cow;
+
+use crate::ast::{Block, ScopeContent, Sheet, StyleAttribute};
+
+/// A type that can be represented as a set of CSS custom properties.
+///
+/// This is used by [`ThemeProvider`](crate::yew::ThemeProvider) to apply a theme to the document
+/// as custom properties, so styles can refer to values of a theme with `var(--name)` and do not
+/// need to be recreated when the theme changes.
+///
+/// # Example
+///
+/// ```
+/// use stylist::ast::ToStyleStr;
+/// use stylist::CssVars;
+///
+/// struct Theme {
+///     primary: String,
+/// }
+///
+/// impl CssVars for Theme {
+///     fn css_vars(&self) -> Vec<(String, String)> {
+///         vec![("--theme-primary".to_string(), self.primary.clone())]
+///     }
+/// }
+///
+/// let theme = Theme {
+///     primary: "red".to_string(),
+/// };
+///
+/// assert_eq!(
+///     theme.to_sheet().to_style_str(None),
+///     ":root {\n    --theme-primary: red;\n}\n"
+/// );
+/// ```
+pub trait CssVars {
+    /// Returns the names and the values of custom properties.
+    ///
+    /// Names include the leading `--`.
+    fn css_vars(&self) -> Vec<(String, String)>;
+
+    /// Returns the names and the values of custom properties with names that start with
+    /// `prefix`.
+    ///
+    /// This is used by the derive macro of [`CssVars`] to name the custom properties of nested
+    /// structs. The default implementation ignores the prefix and returns [`CssVars::css_vars`].
+    #[doc(hidden)]
+    fn css_vars_with_prefix(&self, prefix: &str) -> Vec<(String, String)> {
+        let _ = prefix;
+        self.css_vars()
+    }
+
+    /// Returns a stylesheet that declares the custom properties as dangling attributes.
+    ///
+    /// The attributes are applied to `:root` in a [`GlobalStyle`](crate::GlobalStyle) and to the
+    /// element of the class in a [`Style`](crate::Style).
+    fn to_sheet(&self) -> Sheet {
+        let attrs = self
+            .css_vars()
+            .into_iter()
+            .map(|(key, value)| {
+                StyleAttribute {
+                    key: key.into(),
+                    value: vec![value.into()].into(),
+                }
+                .into()
+            })
+            .collect::<Vec<_>>();
+
+        Sheet::from(vec![ScopeContent::Block(Block {
+            condition: Cow::Borrowed(&[]),
+            content: attrs.into(),
+        })])
+    }
+}
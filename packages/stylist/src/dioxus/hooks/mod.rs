@@ -0,0 +1,56 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::hold::{HoldStyle, StyleHold};
+use crate::manager::StyleManager;
+use crate::StyleSource;
+
+mod use_global_style;
+mod use_style;
+
+pub use use_global_style::use_global_style;
+pub use use_style::use_style;
+
+/// The style created by [`use_source`] and the source it has been created from.
+struct SourceState<S> {
+    css: StyleSource,
+    mgr: StyleManager,
+    style: S,
+    // Released when the style is replaced or the component is dropped.
+    _hold: StyleHold,
+}
+
+/// Creates a style with `create` and claims it until the css or the manager changes or the
+/// component is dropped.
+///
+/// A style used by multiple components is unregistered after the last of them releases it.
+fn use_source<S, F>(css: StyleSource, mgr: StyleManager, create: F) -> S
+where
+    S: HoldStyle + Clone + 'static,
+    F: FnOnce(StyleSource, StyleManager) -> S,
+{
+    let state = use_hook(|| Rc::new(RefCell::new(None::<SourceState<S>>)));
+    use_drop({
+        let state = state.clone();
+        move || drop(state.borrow_mut().take())
+    });
+
+    let mut state = state.borrow_mut();
+    if let Some(ref m) = *state {
+        if m.css == css && m.mgr == mgr {
+            return m.style.clone();
+        }
+    }
+
+    let style = create(css.clone(), mgr.clone());
+    *state = Some(SourceState {
+        css,
+        mgr,
+        _hold: style.hold(),
+        style: style.clone(),
+    });
+
+    style
+}
@@ -0,0 +1,44 @@
+use stylist_core::ResultDisplay;
+
+use super::super::use_style_manager;
+use super::use_source;
+use crate::manager::StyleManager;
+use crate::{Style, StyleSource};
+
+fn create_style(css: StyleSource, mgr: StyleManager) -> Style {
+    Style::new_unpinned(css, &mgr).expect_display("failed to create style")
+}
+
+/// A hook to create auto updating [`Style`]s.
+///
+/// The style is only created again when the css or the
+/// [`StyleManager`](crate::manager::StyleManager) changes. When the style changes, the previous
+/// style is unregistered. The style is also unregistered when the component is dropped. A style
+/// used by multiple components is unregistered after the last of them releases it.
+///
+/// # Example
+///
+/// ```
+/// use dioxus::prelude::*;
+/// use stylist::dioxus::use_style;
+///
+/// #[component]
+/// fn Comp() -> Element {
+///     let style = use_style("color: red;");
+///
+///     rsx! { div { class: style, "Hello world!" } }
+/// }
+/// ```
+pub fn use_style<Css>(css: Css) -> Style
+where
+    Css: TryInto<StyleSource>,
+    crate::Error: From<Css::Error>,
+{
+    let mgr = use_style_manager();
+    let css: StyleSource = css
+        .try_into()
+        .map_err(crate::Error::from)
+        .expect_display("failed to create style");
+
+    use_source(css, mgr, create_style)
+}
@@ -0,0 +1,69 @@
+//! This module contains dioxus specific features.
+//!
+//! Styles are created with the [`StyleManager`](crate::manager::StyleManager) provided by the
+//! nearest [`ManagerProvider`] and can be used as values of `class` attributes.
+//!
+//! # Server-side Rendering
+//!
+//! Provide a manager for each render and insert the styles collected by the manager into the
+//! `<head />` element after the render is finished:
+//!
+//! ```
+//! use dioxus::prelude::*;
+//! use stylist::dioxus::{use_style, ManagerProvider};
+//! use stylist::manager::{CounterClassNameGenerator, StyleManager};
+//!
+//! #[component]
+//! fn Content() -> Element {
+//!     let style = use_style("color: red;");
+//!
+//!     rsx! { div { class: style, "Hello World!" } }
+//! }
+//!
+//! #[component]
+//! fn App(manager: StyleManager) -> Element {
+//!     rsx! {
+//!         ManagerProvider { manager, Content {} }
+//!     }
+//! }
+//!
+//! let manager = StyleManager::builder()
+//!     .class_name_generator(CounterClassNameGenerator::new())
+//!     .build()
+//!     .unwrap();
+//!
+//! let mut dom = VirtualDom::new_with_props(
+//!     App,
+//!     AppProps {
+//!         manager: manager.clone(),
+//!     },
+//! );
+//! dom.rebuild_in_place();
+//!
+//! // Render the body with `dioxus-ssr`, then insert the styles into `<head />`.
+//! let head = manager.render_style_tags();
+//! assert!(head.contains("color: red;"));
+//! ```
+
+use dioxus::dioxus_core::prelude::IntoAttributeValue;
+use dioxus::dioxus_core::AttributeValue;
+
+use crate::{Style, StyleSource};
+
+mod hooks;
+mod provider;
+
+pub use hooks::*;
+pub use provider::{use_style_manager, ManagerProvider, ManagerProviderProps};
+
+impl IntoAttributeValue for Style {
+    fn into_value(self) -> AttributeValue {
+        AttributeValue::Text(self.get_class_name().to_string())
+    }
+}
+
+impl IntoAttributeValue for StyleSource {
+    fn into_value(self) -> AttributeValue {
+        self.into_style().into_value()
+    }
+}
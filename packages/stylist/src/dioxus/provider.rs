@@ -0,0 +1,45 @@
+use dioxus::prelude::*;
+
+use crate::manager::StyleManager;
+
+/// Returns the [`StyleManager`] provided by the nearest [`ManagerProvider`] or the default
+/// manager if no manager is provided.
+///
+/// The component is re-rendered when the provided manager changes.
+pub fn use_style_manager() -> StyleManager {
+    try_use_context::<ReadOnlySignal<StyleManager>>()
+        .map(|m| m.read().clone())
+        .unwrap_or_default()
+}
+
+/// A Context Provider to provide a custom [`StyleManager`] to child components.
+///
+/// Child components that use the manager are re-rendered when the `manager` property changes.
+///
+/// # Example:
+///
+/// ```
+/// use dioxus::prelude::*;
+/// use stylist::dioxus::ManagerProvider;
+/// use stylist::manager::StyleManager;
+///
+/// #[component]
+/// fn App() -> Element {
+///     let manager = use_hook(|| {
+///         StyleManager::builder()
+///             .prefix("my-styles".into())
+///             .build()
+///             .unwrap()
+///     });
+///
+///     rsx! {
+///         ManagerProvider { manager, div { "Hello World!" } }
+///     }
+/// }
+/// ```
+#[component]
+pub fn ManagerProvider(manager: ReadOnlySignal<StyleManager>, children: Element) -> Element {
+    use_context_provider(|| manager);
+
+    children
+}
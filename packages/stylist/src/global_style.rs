@@ -3,11 +3,10 @@ use std::rc::Rc;
 #[cfg(all(debug_assertions, feature = "parser"))]
 use stylist_core::ResultDisplay;
 
-use crate::ast::ToStyleStr;
-use crate::manager::StyleManager;
-use crate::registry::StyleKey;
+use crate::hold::HoldStyle;
+use crate::manager::{ClassNameContext, StyleManager};
+use crate::registry::{StyleKey, StyleKind};
 use crate::style::{StyleContent, StyleId};
-use crate::utils::get_entropy;
 use crate::{Result, StyleSource};
 
 /// A struct that represents a global Style.
@@ -21,16 +20,23 @@ pub struct GlobalStyle {
     inner: Rc<StyleContent>,
 }
 
+impl HoldStyle for GlobalStyle {
+    fn content(&self) -> &Rc<StyleContent> {
+        &self.inner
+    }
+}
+
 impl GlobalStyle {
     // The big method is monomorphic, so less code duplication and code bloat through generics
     // and inlining
     fn create_impl(css: StyleSource, manager: StyleManager) -> Result<Self> {
         let prefix = format!("{}-global", manager.prefix());
+        let name = css.name().map(|m| m.to_string());
         let css = css.into_sheet();
 
         // Creates the StyleKey, return from registry if already cached.
         let key = StyleKey {
-            is_global: true,
+            kind: StyleKind::Global,
             prefix: prefix.into(),
             ast: css,
         };
@@ -42,7 +48,14 @@ impl GlobalStyle {
             return Ok(Self { inner: m });
         }
 
-        let style_str = key.ast.to_style_str(None);
+        let id = manager.generate_class_name(&ClassNameContext {
+            prefix: &key.prefix,
+            name: name.as_deref(),
+            kind: StyleKind::Global,
+            registry: &reg,
+        });
+
+        let style_str = StyleKind::Global.render(&id, &key.ast)?;
 
         // We parse the style str again in debug mode to ensure that interpolated values are
         // not corrupting the stylesheet.
@@ -52,14 +65,8 @@ impl GlobalStyle {
             .expect_display("debug: Stylist failed to parse the style with interpolated values");
 
         let new_style = Self {
-            inner: StyleContent {
-                is_global: true,
-                id: StyleId(format!("{}-{}", key.prefix, get_entropy())),
-                style_str,
-                manager,
-                key: Rc::new(key),
-            }
-            .into(),
+            inner: StyleContent::new(StyleKind::Global, id, Rc::new(key), style_str, manager)
+                .into(),
         };
 
         new_style.inner.manager().mount(&new_style.inner)?;
@@ -96,7 +103,25 @@ impl GlobalStyle {
         M: Into<StyleManager>,
     {
         let mgr = manager.into();
-        Self::create_impl(css.try_into()?, mgr)
+        let style = Self::create_impl(css.try_into()?, mgr)?;
+        // Styles created by users may be used without a claim.
+        style.inner.pin();
+
+        Ok(style)
+    }
+
+    /// Creates a style that is only kept registered while it is claimed with a
+    /// [`StyleHold`](crate::hold::StyleHold), unless it is also created elsewhere.
+    #[cfg_attr(
+        not(any(
+            feature = "yew",
+            feature = "leptos_integration",
+            feature = "dioxus_integration"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) fn new_unpinned(css: StyleSource, manager: &StyleManager) -> Result<Self> {
+        Self::create_impl(css, manager.clone())
     }
 
     /// Get the parsed and generated style in `&str`.
@@ -140,6 +165,19 @@ impl GlobalStyle {
     pub fn id(&self) -> &StyleId {
         self.inner.id()
     }
+
+    /// Replaces the stylesheet of current style while keeping its [`StyleId`].
+    ///
+    /// Please see [`Style::replace`](crate::Style::replace) for details.
+    pub fn replace<Css>(&mut self, css: Css) -> Result<()>
+    where
+        Css: TryInto<StyleSource>,
+        crate::Error: From<Css::Error>,
+    {
+        self.inner = self.inner.replace(css.try_into()?.into_sheet())?;
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,143 @@
+use std::rc::Rc;
+
+use crate::style::StyleContent;
+
+/// A claim on the registration of a style.
+///
+/// Components claim the styles they create while they are mounted instead of unregistering them
+/// when they are unmounted. A style is only unregistered when the last claim on it is dropped, so
+/// components that resolve to the same style do not unregister it for each other.
+///
+/// Styles that have also been handed out without a claim (e.g.: by [`Style::new`](crate::Style))
+/// are pinned and stay registered, as they may be in use as a class name.
+#[derive(Debug)]
+pub(crate) struct StyleHold {
+    content: Rc<StyleContent>,
+}
+
+impl StyleHold {
+    pub fn new(content: Rc<StyleContent>) -> Self {
+        content.add_holder();
+
+        Self { content }
+    }
+}
+
+impl Drop for StyleHold {
+    fn drop(&mut self) {
+        if self.content.remove_holder() && !self.content.is_pinned() {
+            let reg = self.content.manager().get_registry();
+            let mut reg = reg.borrow_mut();
+            reg.unregister_content(&self.content);
+        }
+    }
+}
+
+/// A style that can be claimed with a [`StyleHold`].
+pub(crate) trait HoldStyle {
+    fn content(&self) -> &Rc<StyleContent>;
+
+    fn hold(&self) -> StyleHold {
+        StyleHold::new(self.content().clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manager::StyleManager;
+    use crate::{GlobalStyle, Style, StyleSource};
+
+    fn source(css: &str) -> StyleSource {
+        css.parse().expect("Failed to parse style.")
+    }
+
+    #[test]
+    fn test_shared_hold() {
+        let mgr = StyleManager::builder()
+            .build()
+            .expect("Failed to create manager.");
+
+        let style_a =
+            Style::new_unpinned(source("color: red;"), &mgr).expect("Failed to create Style.");
+        let style_b =
+            Style::new_unpinned(source("color: red;"), &mgr).expect("Failed to create Style.");
+        let hold_a = style_a.hold();
+        let hold_b = style_b.hold();
+
+        // The style is kept registered until the last holder releases it.
+        drop(hold_a);
+        assert_eq!(mgr.style_count(), 1);
+        drop(hold_b);
+        assert_eq!(mgr.style_count(), 0);
+
+        let global = GlobalStyle::new_unpinned(source("body { margin: 0; }"), &mgr)
+            .expect("Failed to create GlobalStyle.");
+        drop(global.hold());
+        assert_eq!(mgr.style_count(), 0);
+    }
+
+    #[test]
+    fn test_stale_hold() {
+        let mgr = StyleManager::builder()
+            .build()
+            .expect("Failed to create manager.");
+
+        let stale =
+            Style::new_unpinned(source("color: red;"), &mgr).expect("Failed to create Style.");
+        drop(stale.hold());
+
+        // A style registered again with the same stylesheet is not unregistered by a claim on the
+        // style it has replaced.
+        let style =
+            Style::new_unpinned(source("color: red;"), &mgr).expect("Failed to create Style.");
+        let hold = style.hold();
+        drop(stale.hold());
+        assert_eq!(mgr.style_count(), 1);
+
+        drop(hold);
+        assert_eq!(mgr.style_count(), 0);
+    }
+
+    #[test]
+    fn test_pinned_hold() {
+        let mgr = StyleManager::builder()
+            .build()
+            .expect("Failed to create manager.");
+
+        let style =
+            Style::new_unpinned(source("color: red;"), &mgr).expect("Failed to create Style.");
+        let hold = style.hold();
+
+        // A style that has also been created by the user stays registered after claims are
+        // released.
+        let _user_style =
+            Style::new_with_manager("color: red;", &mgr).expect("Failed to create Style.");
+        drop(hold);
+        assert_eq!(mgr.style_count(), 1);
+    }
+
+    #[test]
+    fn test_atomic_hold() {
+        let mgr = StyleManager::builder()
+            .atomic(true)
+            .build()
+            .expect("Failed to create manager.");
+
+        let shared = Style::new_unpinned(source("display: flex; color: red;"), &mgr)
+            .expect("Failed to create Style.");
+        let shared_hold = shared.hold();
+
+        // The atoms of a style whose value has changed are released with it.
+        for width in ["1px", "2px", "3px"] {
+            let style =
+                Style::new_unpinned(source(&format!("display: flex; width: {};", width)), &mgr)
+                    .expect("Failed to create Style.");
+            drop(style.hold());
+        }
+        assert_eq!(mgr.style_count(), 2);
+
+        drop(shared_hold);
+        assert_eq!(mgr.style_count(), 0);
+    }
+}
@@ -0,0 +1,318 @@
+use std::fmt;
+use std::rc::Rc;
+
+#[cfg(all(debug_assertions, feature = "parser"))]
+use stylist_core::ResultDisplay;
+
+use crate::ast::{Rule, RuleBlockContent, ScopeContent, Sheet, ToStyleStr};
+use crate::manager::{ClassNameContext, StyleManager};
+use crate::registry::{StyleKey, StyleKind};
+use crate::style::{StyleContent, StyleId};
+use crate::{Error, Result, StyleSource};
+
+/// A struct that represents a `@keyframes` rule with a generated animation name.
+///
+/// This struct is equivalent to [`Style`](crate::Style) but for keyframes. The source of keyframes
+/// only contains frames (`from`, `to` and percentage blocks). The animation name is generated by
+/// the [`StyleManager`] and can be interpolated into other styles as [`Keyframes`] implements
+/// [`Display`](std::fmt::Display).
+///
+/// Like a [`Style`](crate::Style), the keyframes are kept registered by the [`StyleManager`] and
+/// stay mounted after all clones of it are dropped. They are only unmounted after
+/// [`Keyframes::unregister`] has been called and all clones of it are dropped, so keyframes that
+/// are still used by other styles should not be unregistered.
+///
+/// # Example
+///
+/// ```
+/// use stylist::{css, Keyframes, Style};
+///
+/// let fade = Keyframes::new("from { opacity: 0; } to { opacity: 1; }")?;
+/// let style = Style::new(css!(animation-name: ${fade}; animation-duration: 1s;))?;
+/// # Ok::<(), stylist::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Keyframes {
+    inner: Rc<StyleContent>,
+}
+
+impl Keyframes {
+    // The big method is monomorphic, so less code duplication and code bloat through generics
+    // and inlining
+    fn create_impl(css: StyleSource, manager: StyleManager) -> Result<Self> {
+        let prefix = format!("{}-keyframes", manager.prefix());
+        let name = css.name().map(|m| m.to_string());
+        let css = css.into_sheet();
+
+        // Creates the StyleKey, return from registry if already cached.
+        let key = StyleKey {
+            kind: StyleKind::Keyframes,
+            prefix: prefix.into(),
+            ast: css,
+        };
+
+        let reg = manager.get_registry();
+        let mut reg = reg.borrow_mut();
+
+        if let Some(m) = reg.get(&key) {
+            return Ok(Self { inner: m });
+        }
+
+        let id = manager.generate_class_name(&ClassNameContext {
+            prefix: &key.prefix,
+            name: name.as_deref(),
+            kind: StyleKind::Keyframes,
+            registry: &reg,
+        });
+
+        let style_str = StyleKind::Keyframes.render(&id, &key.ast)?;
+
+        // We parse the style str again in debug mode to ensure that interpolated values are
+        // not corrupting the stylesheet.
+        #[cfg(all(debug_assertions, feature = "parser"))]
+        style_str
+            .parse::<crate::ast::Sheet>()
+            .expect_display("debug: Stylist failed to parse the style with interpolated values");
+
+        let new_style = Self {
+            inner: StyleContent::new(StyleKind::Keyframes, id, Rc::new(key), style_str, manager)
+                .into(),
+        };
+
+        new_style.inner.manager().mount(&new_style.inner)?;
+
+        // Register the created Style.
+        reg.register(new_style.inner.clone());
+
+        Ok(new_style)
+    }
+
+    /// Creates new keyframes from some parsable frames.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stylist::Keyframes;
+    ///
+    /// let keyframes = Keyframes::new("from { opacity: 0; } 50% { opacity: 0.5; } to { opacity: 1; }")?;
+    /// # Ok::<(), stylist::Error>(())
+    /// ```
+    pub fn new<Css>(css: Css) -> Result<Self>
+    where
+        Css: TryInto<StyleSource>,
+        crate::Error: From<Css::Error>,
+    {
+        Self::new_with_manager(css, StyleManager::default())
+    }
+
+    /// Creates new keyframes using a custom manager.
+    pub fn new_with_manager<Css, M>(css: Css, manager: M) -> Result<Self>
+    where
+        Css: TryInto<StyleSource>,
+        crate::Error: From<Css::Error>,
+        M: Into<StyleManager>,
+    {
+        let mgr = manager.into();
+        Self::create_impl(css.try_into()?, mgr)
+    }
+
+    /// Returns the generated animation name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stylist::Keyframes;
+    ///
+    /// let keyframes = Keyframes::new("from { opacity: 0; } to { opacity: 1; }")?;
+    ///
+    /// // Example Output: stylist-keyframes-uSu9NZZu
+    /// println!("{}", keyframes.name());
+    /// # Ok::<(), stylist::Error>(())
+    /// ```
+    pub fn name(&self) -> &str {
+        self.inner.id()
+    }
+
+    /// Get the generated `@keyframes` rule in `&str`.
+    ///
+    /// This is usually used for debug purposes or testing in non-wasm32 targets.
+    pub fn get_style_str(&self) -> &str {
+        self.inner.get_style_str()
+    }
+
+    /// Returns a reference of style key.
+    pub(crate) fn key(&self) -> Rc<StyleKey> {
+        self.inner.key()
+    }
+
+    /// Unregister current keyframes from style registry.
+    ///
+    /// After calling this method, the keyframes will be unmounted from DOM after all its clones
+    /// are freed.
+    pub fn unregister(&self) {
+        let reg = self.inner.manager().get_registry();
+        let mut reg = reg.borrow_mut();
+        reg.unregister(self.key());
+    }
+
+    /// Returns the [`StyleId`] for current keyframes.
+    pub fn id(&self) -> &StyleId {
+        self.inner.id()
+    }
+
+    /// Replaces the frames of current keyframes while keeping the animation name.
+    ///
+    /// Please see [`Style::replace`](crate::Style::replace) for details.
+    pub fn replace<Css>(&mut self, css: Css) -> Result<()>
+    where
+        Css: TryInto<StyleSource>,
+        crate::Error: From<Css::Error>,
+    {
+        self.inner = self.inner.replace(css.try_into()?.into_sheet())?;
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Keyframes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+// Returns `true` if the selector of a frame is valid (`from`, `to` or a percentage).
+fn is_frame_selector(s: &str) -> bool {
+    match s {
+        "from" | "to" => true,
+        _ => s
+            .strip_suffix('%')
+            .and_then(|m| m.trim().parse::<f64>().ok())
+            .map(|m| (0.0..=100.0).contains(&m))
+            .unwrap_or(false),
+    }
+}
+
+/// Creates a sheet with a `@keyframes` rule from a sheet that only contains frames.
+pub(crate) fn to_keyframes_sheet(name: &str, ast: &Sheet) -> Result<Sheet> {
+    let error = |reason: String| Error::Keyframes { reason };
+
+    let mut frames = Vec::new();
+
+    for scope in ast.iter() {
+        let block = match scope {
+            ScopeContent::Block(ref m) => m,
+            ScopeContent::Rule(_) => {
+                return Err(error("at-rules are not allowed in keyframes".into()));
+            }
+        };
+
+        if block.condition.is_empty() {
+            return Err(error(
+                "style attributes must be in a frame (e.g.: `from { ... }`)".into(),
+            ));
+        }
+
+        let mut selectors = Vec::new();
+        for selector in block.condition.iter() {
+            let selector = selector.to_style_str(None);
+            let selector = selector.trim();
+
+            if !is_frame_selector(selector) {
+                return Err(error(format!(
+                    "`{}` is not a valid frame selector, expected `from`, `to` or a percentage",
+                    selector
+                )));
+            }
+
+            selectors.push(selector.to_string());
+        }
+
+        if block
+            .content
+            .iter()
+            .any(|m| !matches!(m, RuleBlockContent::StyleAttr(_)))
+        {
+            return Err(error("frames can only contain style attributes".into()));
+        }
+
+        frames.push(RuleBlockContent::Rule(
+            Rule {
+                condition: vec![selectors.join(", ").into()].into(),
+                content: block.content.clone(),
+            }
+            .into(),
+        ));
+    }
+
+    Ok(Sheet::from(vec![ScopeContent::Rule(Rule {
+        condition: vec![format!("@keyframes {}", name).into()].into(),
+        content: frames.into(),
+    })]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple() {
+        let keyframes =
+            Keyframes::new("from { opacity: 0; } 50%, 75% { opacity: 0.5; } to { opacity: 1; }")
+                .expect("Failed to create Keyframes.");
+
+        assert_eq!(
+            keyframes.get_style_str(),
+            format!(
+                r#"@keyframes {name} {{
+    from {{
+        opacity: 0;
+    }}
+    50%, 75% {{
+        opacity: 0.5;
+    }}
+    to {{
+        opacity: 1;
+    }}
+}}
+"#,
+                name = keyframes.name()
+            )
+        );
+        assert_eq!(keyframes.to_string(), keyframes.name());
+    }
+
+    #[test]
+    fn test_distinct_from_style() {
+        let src = "from { opacity: 0; }";
+        let keyframes = Keyframes::new(src).expect("Failed to create Keyframes.");
+        let style = crate::Style::new(src).expect("Failed to create Style.");
+
+        assert_ne!(keyframes.id(), style.id());
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(matches!(
+            Keyframes::new("opacity: 0;"),
+            Err(Error::Keyframes { .. })
+        ));
+        assert!(matches!(
+            Keyframes::new("div { opacity: 0; }"),
+            Err(Error::Keyframes { .. })
+        ));
+        assert!(matches!(
+            Keyframes::new("120% { opacity: 0; }"),
+            Err(Error::Keyframes { .. })
+        ));
+        assert!(matches!(
+            Keyframes::new("@media screen { from { opacity: 0; } }"),
+            Err(Error::Keyframes { .. })
+        ));
+
+        // Custom properties can not be declared by keyframes.
+        let src = crate::StyleSource::try_from("from { width: var(--a); }")
+            .expect("Failed to parse style.")
+            .with_var("--a", "1px");
+        assert!(matches!(Keyframes::new(src), Err(Error::Keyframes { .. })));
+    }
+}
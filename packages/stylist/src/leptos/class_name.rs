@@ -0,0 +1,112 @@
+use std::fmt;
+use std::sync::Arc;
+
+use leptos::tachys::html::class::IntoClass;
+use leptos::tachys::renderer::types::Element;
+
+use super::hold_until_cleanup;
+use crate::{Style, StyleSource};
+
+/// The class name of a [`Style`] that can be used as the value of a `class` attribute.
+///
+/// Unlike [`Style`], this type is `Send`. It only holds the class name, so the style has to be
+/// kept registered elsewhere (e.g.: by the registry of the manager) for the class to be styled.
+///
+/// A class name created from a [`StyleSource`] claims the style until the current reactive owner
+/// is cleaned up, like [`use_style`](super::use_style).
+///
+/// # Example
+///
+/// ```
+/// use leptos::prelude::*;
+/// use stylist::css;
+/// use stylist::leptos::ClassName;
+///
+/// #[component]
+/// fn Comp() -> impl IntoView {
+///     let class = ClassName::from(css!("color: red;"));
+///
+///     view! { <div class=class>"Hello world!"</div> }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClassName(String);
+
+impl ClassName {
+    /// Returns the class name as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ClassName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<Style> for ClassName {
+    fn from(style: Style) -> Self {
+        Self(style.get_class_name().to_string())
+    }
+}
+
+impl From<StyleSource> for ClassName {
+    fn from(style_src: StyleSource) -> Self {
+        let style = style_src.into_unpinned_style();
+        hold_until_cleanup(&style);
+
+        style.into()
+    }
+}
+
+impl From<ClassName> for String {
+    fn from(class_name: ClassName) -> Self {
+        class_name.0
+    }
+}
+
+impl IntoClass for ClassName {
+    type AsyncOutput = Self;
+    type State = <String as IntoClass>::State;
+    type Cloneable = Arc<str>;
+    type CloneableOwned = Arc<str>;
+
+    fn html_len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn to_html(self, class: &mut String) {
+        self.0.to_html(class);
+    }
+
+    fn hydrate<const FROM_SERVER: bool>(self, el: &Element) -> Self::State {
+        self.0.hydrate::<FROM_SERVER>(el)
+    }
+
+    fn build(self, el: &Element) -> Self::State {
+        self.0.build(el)
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        self.0.rebuild(state);
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        self.0.into()
+    }
+
+    fn into_cloneable_owned(self) -> Self::CloneableOwned {
+        self.0.into()
+    }
+
+    fn dry_resolve(&mut self) {}
+
+    async fn resolve(self) -> Self::AsyncOutput {
+        self
+    }
+
+    fn reset(state: &mut Self::State) {
+        String::reset(state);
+    }
+}
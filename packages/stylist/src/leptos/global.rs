@@ -0,0 +1,34 @@
+use leptos::prelude::*;
+use stylist_core::ResultDisplay;
+
+use super::{hold_until_cleanup, use_style_manager};
+use crate::{GlobalStyle, StyleSource};
+
+/// A Global Style that will be applied to `<html />` tag, inspired by [emotion](https://emotion.sh).
+///
+/// The style is created with the contextual [`StyleManager`](crate::manager::StyleManager) and
+/// removed when the component is disposed. A style used by multiple components is removed after
+/// the last of them is disposed.
+///
+/// # Example:
+///
+/// ```
+/// use leptos::prelude::*;
+/// use stylist::css;
+/// use stylist::leptos::Global;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <Global css=css!("color: red;") />
+///         <div>"Hello World!"</div>
+///     }
+/// }
+/// ```
+#[component]
+pub fn Global(css: StyleSource) -> impl IntoView {
+    let mgr = use_style_manager();
+    let style = GlobalStyle::new_unpinned(css, &mgr).expect_display("failed to create style");
+
+    hold_until_cleanup(&style);
+}
@@ -0,0 +1,5 @@
+mod use_media_query;
+mod use_style;
+
+pub use use_media_query::use_media_query;
+pub use use_style::use_style;
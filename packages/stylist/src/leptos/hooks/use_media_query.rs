@@ -0,0 +1,56 @@
+use leptos::prelude::*;
+
+/// Returns a signal of whether the provided query matches.
+///
+/// The signal is updated when the result changes. When media queries are not available (e.g.:
+/// during server-side rendering), the signal is always `false`.
+///
+/// # Example
+///
+/// ```
+/// use leptos::prelude::*;
+/// use stylist::leptos::use_media_query;
+///
+/// #[component]
+/// fn Comp() -> impl IntoView {
+///     let is_wide = use_media_query("(min-width: 600px)");
+///
+///     view! { <div>{move || if is_wide.get() { "Wide" } else { "Narrow" }}</div> }
+/// }
+/// ```
+pub fn use_media_query(query: &str) -> Signal<bool> {
+    let (matches, set_matches) = signal(false);
+    subscribe(query, set_matches);
+
+    matches.into()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn subscribe(query: &str, set_matches: WriteSignal<bool>) {
+    use gloo_events::EventListener;
+    use send_wrapper::SendWrapper;
+
+    use crate::arch::window;
+
+    let list = match window()
+        .ok()
+        .and_then(|m| m.match_media(query).ok().flatten())
+    {
+        Some(m) => m,
+        None => return,
+    };
+
+    set_matches.set(list.matches());
+
+    let listener = EventListener::new(&list.clone(), "change", move |_event| {
+        set_matches.set(list.matches());
+    });
+
+    let listener = SendWrapper::new(listener);
+    on_cleanup(move || drop(listener));
+}
+
+// There is no window to query outside of the browser.
+#[cfg(not(target_arch = "wasm32"))]
+#[allow(unused_variables)]
+fn subscribe(query: &str, set_matches: WriteSignal<bool>) {}
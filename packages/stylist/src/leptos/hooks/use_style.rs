@@ -0,0 +1,46 @@
+use stylist_core::ResultDisplay;
+
+use super::super::{hold_until_cleanup, use_style_manager};
+use crate::{Style, StyleSource};
+
+/// Creates a [`Style`] with the contextual [`StyleManager`](crate::manager::StyleManager).
+///
+/// Leptos components only run once, use a closure to create styles that depend on signals.
+///
+/// The style is unregistered when the current reactive owner is cleaned up, e.g.: when the
+/// component is disposed or the closure that has created it runs again. A style used by multiple
+/// components is unregistered after the last of them releases it.
+///
+/// # Panics
+///
+/// Panics if the style cannot be created.
+///
+/// # Example
+///
+/// ```
+/// use leptos::prelude::*;
+/// use stylist::leptos::{use_style, ClassName};
+///
+/// #[component]
+/// fn Comp() -> impl IntoView {
+///     let class = ClassName::from(use_style("color: red;"));
+///
+///     view! { <div class=class>"Hello world!"</div> }
+/// }
+/// ```
+pub fn use_style<Css>(css: Css) -> Style
+where
+    Css: TryInto<StyleSource>,
+    crate::Error: From<Css::Error>,
+{
+    let mgr = use_style_manager();
+    let css: StyleSource = css
+        .try_into()
+        .map_err(crate::Error::from)
+        .expect_display("failed to create style");
+
+    let style = Style::new_unpinned(css, &mgr).expect_display("failed to create style");
+    hold_until_cleanup(&style);
+
+    style
+}
@@ -0,0 +1,87 @@
+//! This module contains leptos specific features.
+//!
+//! The module mirrors [`stylist::yew`](crate::yew) and uses the same [`StyleManager`] core.
+//!
+//! # Note
+//!
+//! [`Style`] and [`StyleManager`](crate::manager::StyleManager) are not `Send`. Leptos requires
+//! values of the `class` attribute to be `Send`, so styles are converted into a [`ClassName`]
+//! before they are used as a class.
+//!
+//! Rendering on a single thread is a hard requirement. The manager provided by
+//! [`ManagerProvider`] and the styles of [`Global`] are stored in the leptos context with a
+//! [`SendWrapper`](send_wrapper::SendWrapper): accessing the manager or disposing of the
+//! components from another thread panics. On a multi-threaded server, render each request and
+//! dispose of its owner on one thread (e.g.: with a `LocalSet` of tokio).
+
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+
+use crate::hold::HoldStyle;
+use crate::{Style, StyleSource};
+
+/// An attribute to style a leptos component.
+///
+/// Specifically this introduces a specialized [`css!`](crate::css) macro that is aware of the
+/// contextual style manager provided by [`ManagerProvider`] and applies
+/// [`component`](::leptos::component) to the function.
+///
+/// # Example:
+///
+/// ```
+/// use leptos::prelude::*;
+/// use stylist::leptos::{styled_component, ClassName};
+///
+/// #[styled_component]
+/// fn MyStyledComponent() -> impl IntoView {
+///     let class = ClassName::from(css!("color: red;"));
+///
+///     view! { <div class=class>"Hello World!"</div> }
+/// }
+/// ```
+///
+/// # Note:
+///
+/// You don't need to import [`css!`](crate::css) inside of a `styled_component`.
+#[cfg(feature = "macros")]
+pub use stylist_macros::leptos_styled_component as styled_component;
+
+mod class_name;
+mod global;
+mod hooks;
+mod provider;
+
+pub use class_name::ClassName;
+pub use global::Global;
+pub use hooks::*;
+pub use provider::{use_style_manager, ManagerProvider};
+
+impl From<Style> for String {
+    fn from(style: Style) -> Self {
+        style.get_class_name().to_string()
+    }
+}
+
+impl From<StyleSource> for String {
+    fn from(style_src: StyleSource) -> Self {
+        ClassName::from(style_src).into()
+    }
+}
+
+/// Claims the registration of a style until the current reactive owner is cleaned up.
+///
+/// A closure that creates a style is cleaned up when it runs again, so the style created for a
+/// previous value of a signal is unregistered once it is no longer used. Without an owner, the
+/// style stays registered.
+pub(crate) fn hold_until_cleanup<S>(style: &S)
+where
+    S: HoldStyle,
+{
+    if Owner::current().is_none() {
+        style.content().pin();
+        return;
+    }
+
+    let hold = SendWrapper::new(style.hold());
+    on_cleanup(move || drop(hold));
+}
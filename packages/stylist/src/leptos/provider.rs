@@ -0,0 +1,58 @@
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+
+use crate::manager::StyleManager;
+
+// The manager is not `Send`, it can only be accessed from the thread that provides it.
+#[derive(Debug, Clone)]
+struct ManagerContext(SendWrapper<StyleManager>);
+
+/// Returns the [`StyleManager`] provided by the nearest [`ManagerProvider`] or the default
+/// manager if no manager is provided.
+///
+/// # Panics
+///
+/// Panics if the manager is provided on another thread.
+pub fn use_style_manager() -> StyleManager {
+    use_context::<ManagerContext>()
+        .map(|m| {
+            assert!(
+                m.0.valid(),
+                "the StyleManager of a ManagerProvider is accessed from another thread, \
+                 stylist requires leptos components to be rendered on a single thread"
+            );
+
+            (*m.0).clone()
+        })
+        .unwrap_or_default()
+}
+
+/// A Context Provider to provide a custom [`StyleManager`] to child components.
+///
+/// # Example:
+///
+/// ```
+/// use leptos::prelude::*;
+/// use stylist::leptos::ManagerProvider;
+/// use stylist::manager::StyleManager;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let manager = StyleManager::builder()
+///         .prefix("my-styles".into())
+///         .build()
+///         .unwrap();
+///
+///     view! {
+///         <ManagerProvider manager=manager>
+///             <div>"Hello World!"</div>
+///         </ManagerProvider>
+///     }
+/// }
+/// ```
+#[component]
+pub fn ManagerProvider(manager: StyleManager, children: Children) -> impl IntoView {
+    provide_context(ManagerContext(SendWrapper::new(manager)));
+
+    children()
+}
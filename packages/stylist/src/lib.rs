@@ -80,7 +80,8 @@
 //! }
 //! ```
 //!
-//! You can also use other CSS rules(such as: keyframes, supports and media):
+//! You can also use other CSS rules(such as: keyframes, supports, media, container, layer and
+//! font-face):
 //!
 //! ```css
 //! @keyframes mymove {
@@ -113,6 +114,19 @@
 //! }
 //! ```
 //!
+//! ```css
+//! @layer base, components;
+//!
+//! @container sidebar (min-width: 400px) {
+//!   display: flex;
+//! }
+//!
+//! @font-face {
+//!   font-family: "Open Sans";
+//!   src: url("/fonts/OpenSans.woff2") format("woff2");
+//! }
+//! ```
+//!
 //! ### Theming
 //!
 //! There's theming example using
@@ -126,11 +140,34 @@
 //! - `yew_integration`: This flag enables yew integration, which implements
 //!   [`Classes`](::yew::html::Classes) for [`Style`] and provides a [`Global`](yew::Global)
 //!   component for applying global styles.
+//! - `dioxus_integration`: This flag enables dioxus integration in the [`dioxus`](crate::dioxus)
+//!   module.
+//! - `leptos_integration`: This flag enables leptos integration in the [`leptos`](crate::leptos)
+//!   module, which mirrors the yew integration.
 
-#[cfg(any(feature = "yew_use_media_query", target_arch = "wasm32"))]
+#[cfg(target_arch = "wasm32")]
 mod arch;
 pub mod ast;
+mod atomic;
+mod batch;
+mod class_name;
+mod containers;
+mod css_vars;
+#[cfg(feature = "dioxus_integration")]
+pub mod dioxus;
 mod global_style;
+#[cfg_attr(
+    not(any(
+        feature = "yew",
+        feature = "leptos_integration",
+        feature = "dioxus_integration"
+    )),
+    allow(dead_code)
+)]
+mod hold;
+mod keyframes;
+#[cfg(feature = "leptos_integration")]
+pub mod leptos;
 #[cfg(feature = "macros")]
 pub mod macros;
 pub mod manager;
@@ -138,10 +175,13 @@ mod registry;
 mod style;
 mod style_src;
 mod utils;
+pub mod variants;
 #[cfg(feature = "yew")]
 pub mod yew;
 
+pub use css_vars::CssVars;
 pub use global_style::GlobalStyle;
+pub use keyframes::Keyframes;
 pub use style::Style;
 pub use style_src::StyleSource;
 #[doc(inline)]
@@ -180,6 +220,33 @@ pub use stylist_macros::css;
 /// ```
 #[cfg(feature = "macros")]
 pub use stylist_macros::global_style;
+/// A procedural macro that parses a string literal or an inline stylesheet into a [`Keyframes`].
+///
+/// The stylesheet may only contain frames (`from`, `to` and percentage blocks).
+///
+/// Please consult the documentation of the [`macros`] module for the supported syntax of this
+/// macro.
+///
+/// # Example
+///
+/// ```
+/// use stylist::{css, keyframes, Style};
+///
+/// // Returns a Keyframes instance.
+/// let fade = keyframes!(
+///     from {
+///         opacity: 0;
+///     }
+///     to {
+///         opacity: 1;
+///     }
+/// )
+/// .expect("Failed to create keyframes.");
+///
+/// let style = Style::new(css!(animation-name: ${fade}; animation-duration: 1s;));
+/// ```
+#[cfg(feature = "macros")]
+pub use stylist_macros::keyframes;
 /// A procedural macro that parses a string literal or an inline stylesheet into a [`Style`].
 ///
 /// Please consult the documentation of the [`macros`] module for the supported syntax of this
@@ -195,3 +262,65 @@ pub use stylist_macros::global_style;
 /// ```
 #[cfg(feature = "macros")]
 pub use stylist_macros::style;
+/// A derive macro that maps the fields of a struct to CSS custom properties.
+///
+/// Each field becomes a custom property named `--{prefix}-{field}`, where the prefix defaults to
+/// the name of the struct in kebab case and field names are converted to kebab case. The values
+/// are rendered with [`ToString`]. Fields of structs that also derive [`CssVars`] can be marked as
+/// nested and are named `--{prefix}-{field}-{nested field}`.
+///
+/// The macro also generates a `{Struct}Vars` struct with an accessor for each field that returns
+/// `var(--{prefix}-{field})`. `{Struct}::vars()` returns an instance of it. This can be
+/// interpolated into [`css!`] so references to custom properties are checked at compile time.
+///
+/// The following attributes are supported:
+///
+/// - `#[css_vars(prefix = "...")]` on the struct sets the prefix.
+/// - `#[css_vars(rename = "...")]` on a field sets the name of the field.
+/// - `#[css_vars(nested)]` on a field maps a nested struct.
+/// - `#[css_vars(skip)]` on a field skips the field.
+///
+/// # Example
+///
+/// ```
+/// use stylist::{css, CssVars};
+///
+/// #[derive(CssVars)]
+/// struct Spacing {
+///     small: String,
+/// }
+///
+/// #[derive(CssVars)]
+/// #[css_vars(prefix = "theme")]
+/// struct Theme {
+///     primary_color: String,
+///     #[css_vars(nested)]
+///     spacing: Spacing,
+/// }
+///
+/// let theme = Theme {
+///     primary_color: "red".to_string(),
+///     spacing: Spacing {
+///         small: "4px".to_string(),
+///     },
+/// };
+///
+/// assert_eq!(
+///     theme.css_vars(),
+///     vec![
+///         ("--theme-primary-color".to_string(), "red".to_string()),
+///         ("--theme-spacing-small".to_string(), "4px".to_string()),
+///     ]
+/// );
+///
+/// let vars = Theme::vars();
+/// assert_eq!(vars.primary_color(), "var(--theme-primary-color)");
+/// assert_eq!(vars.spacing().small(), "var(--theme-spacing-small)");
+///
+/// let s = css!(
+///     color: ${vars.primary_color()};
+///     padding: ${vars.spacing().small()};
+/// );
+/// ```
+#[cfg(feature = "macros")]
+pub use stylist_macros::CssVars;
@@ -9,11 +9,19 @@
 //! The first argument of this syntax is a string literal followed by an argument list. This macro
 //! will replace `${arg}` with the argument in the argument list when creating the AST.
 //!
-//! This syntax supports interpolation on values of style attributes, selectors, `@supports` and
-//! `@media` rules.
+//! This syntax supports interpolation on values of style attributes, selectors, `@supports`,
+//! `@media`, `@container` and `@layer` rules.
 //!
 //! Interpolated strings are denoted with `${ident}` and any type that implements [`Display`] can be
-//! used as value. Only named argument are supported at this moment.
+//! used as value.
+//!
+//! Arguments can be passed by name (`color = "red"`) or by position (`"red"`), positional
+//! arguments are referred to by their index (`${0}`) and have to come before named arguments. An
+//! interpolation that does not match any argument captures the variable of the same name in the
+//! surrounding scope, like [`format!`] does. Fields and methods without arguments of an argument or
+//! a captured variable can be accessed with a path (`${theme.primary}`, `${theme.radius()}`).
+//!
+//! All arguments that are passed explicitly have to be used.
 //!
 //! If you do need to print a `${` sequence, you may use `$${` to escape to a `${`.
 //!
@@ -79,6 +87,35 @@
 //! `${expr}` syntax. In distinction, the braces contain a rust expression of any type implementing
 //! [`Display`] will be evaluated in the surrounding context.
 //!
+//! ## Nested Blocks
+//!
+//! Blocks can be nested in other blocks and are expanded when the macro is expanded. The current
+//! selector (`&`) in a nested selector refers to the parent selector, other nested selectors select
+//! descendants of the parent. Rules such as `@media` can also be nested in blocks.
+//!
+//! If the parent block has multiple selectors, each `&` stands for any of them, like in native CSS
+//! nesting. `.a, .b { & + & {} }` is expanded to `.a + .a, .a + .b, .b + .a, .b + .b`.
+//!
+//! ```
+//! use stylist::css;
+//!
+//! let s = css!(
+//!     .card {
+//!         color: black;
+//!
+//!         // .card:hover
+//!         &:hover {
+//!             color: red;
+//!         }
+//!
+//!         // .card .title
+//!         .title {
+//!             font-weight: bold;
+//!         }
+//!     }
+//! );
+//! ```
+//!
 //! ## Known Limitations
 //!
 //! ### Dimensions
@@ -158,6 +195,66 @@
 //! println!("{}", style.get_style_str());
 //! ```
 //!
+//! ## CSS Custom Properties
+//!
+//! Every distinct value of an interpolated expression creates a new stylesheet and a new class.
+//! If a value changes often, start the [`css!`](crate::css) macro with `#![css_vars]`. Dynamic
+//! interpolations in attribute values are then compiled to CSS custom properties (`var(--s-*)`),
+//! so that the stylesheet and the class name are the same no matter the values. The values are
+//! carried by the returned [`StyleSource`](crate::StyleSource) and are applied to the element with
+//! [`StyleSource::split_vars`](crate::StyleSource::split_vars). Components created with
+//! [`styled!`](crate::yew::styled) and the
+//! [`use_style_with_vars`](crate::yew::use_style_with_vars) hook apply them to the `style`
+//! attribute of the element.
+//!
+//! **Warning:** A source that still carries custom properties can be used as a class (e.g.:
+//! `class={css!(#![css_vars] ...)}`) or to create a [`Style`](crate::Style),
+//! [`GlobalStyle`](crate::GlobalStyle) or [`use_style`](crate::yew::use_style) directly, but the
+//! custom properties are then declared by the style. Each distinct value creates a new style and a
+//! new `<style />` element again, so `#![css_vars]` gives no benefit there.
+//!
+//! An interpolated expression has to produce a complete value (e.g.: `${"100px"}` instead of
+//! `${100}px`). Interpolations in selectors, at-rules and attribute names, and literal values are
+//! not affected.
+//!
+//! ```
+//! use stylist::css;
+//! use yew::prelude::*;
+//!
+//! let color = "red";
+//! let (s, vars) = css!(
+//!     #![css_vars]
+//!     color: ${color};
+//! )
+//! .split_vars();
+//!
+//! let rendered = html! {<div class={s} style={vars} />};
+//! ```
+//!
+//! ## Property Validation
+//!
+//! Property names of the [inline] syntax are checked against a list of known properties when the
+//! macro is expanded, and values of properties that only accept keywords (e.g.: `display`,
+//! `position`) are checked if they are made of identifiers. Typos are reported at the offending
+//! token with a suggestion:
+//!
+//! ```text
+//! error: invalid value `flexx` for property `display`, did you mean `flex`?
+//! ```
+//!
+//! Custom properties (`--*`), vendor prefixed names and values (`-webkit-*`) and interpolated
+//! names are not checked. To use custom or experimental properties, start the macro with
+//! `#![unchecked]`:
+//!
+//! ```
+//! use stylist::css;
+//!
+//! let s = css!(
+//!     #![unchecked]
+//!     experimental-property: value;
+//! );
+//! ```
+//!
 //! ## Security Notice
 //!
 //! Stylist currently does not check or escape the content of interpolated strings. It is possible
@@ -4,6 +4,9 @@
 //! mounting point / mounting behaviour for styles (when rendering contents into a `ShadowRoot` or
 //! an `<iframe />`).
 //!
+//! A manager can also mount its styles into multiple containers (e.g.: one `ShadowRoot` per
+//! widget) while sharing one registry, see [`StyleManager::add_container`].
+//!
 //! This is an advanced feature and most of the time you don't need to use it.
 
 use std::borrow::Cow;
@@ -12,12 +15,27 @@ use std::rc::Rc;
 
 use once_cell::unsync::Lazy;
 use stylist_core::ResultDisplay;
-use web_sys::Node;
+use web_sys::{Element, Node};
 
-use crate::registry::StyleRegistry;
+pub use crate::batch::MountMode;
+use crate::batch::{self, Backend, PendingOps};
+pub use crate::class_name::{
+    ClassNameContext, ClassNameGenerator, CounterClassNameGenerator, DebugClassNameGenerator,
+    DefaultClassNameGenerator,
+};
+use crate::containers::Containers;
+pub use crate::registry::RegisteredStyle;
+use crate::registry::{StyleBucket, StyleKind, StyleRegistry};
 use crate::style::StyleContent;
 pub use crate::style::StyleId;
-use crate::Result;
+use crate::utils::{escape_html_attr, escape_style_text, is_valid_attribute_name};
+use crate::{Result, Style};
+
+const STYLE_ATTR: &str = "data-style";
+// The attribute of `<style />` tags of atomic styles that are placed after other atomic styles.
+const CONDITIONAL_ATTR: &str = "data-style-conditional";
+// The attribute of `<style />` tags rendered on the server that have not been adopted yet.
+const RENDERED_ATTR: &str = "data-style-rendered";
 
 /// A builder for [`StyleManager`].
 #[derive(Debug, Clone)]
@@ -26,8 +44,15 @@ pub struct StyleManagerBuilder {
 
     prefix: Cow<'static, str>,
     container: Option<Node>,
+    class_name_generator: Rc<dyn ClassNameGenerator>,
+
+    nonce: Option<Cow<'static, str>>,
+    attributes: Vec<(Cow<'static, str>, Cow<'static, str>)>,
 
     append: bool,
+    mount_mode: MountMode,
+    atomic: bool,
+    hydrate: bool,
 }
 
 impl Default for StyleManagerBuilder {
@@ -36,7 +61,13 @@ impl Default for StyleManagerBuilder {
             registry: Rc::default(),
             prefix: "stylist".into(),
             container: None,
+            class_name_generator: Rc::new(DefaultClassNameGenerator),
+            nonce: None,
+            attributes: Vec::new(),
             append: true,
+            mount_mode: MountMode::default(),
+            atomic: false,
+            hydrate: false,
         }
     }
 }
@@ -66,6 +97,51 @@ impl StyleManagerBuilder {
         self
     }
 
+    /// Set the [`ClassNameGenerator`] used to generate class names of styles managed by this
+    /// manager.
+    ///
+    /// Default: [`DefaultClassNameGenerator`]
+    pub fn class_name_generator<G>(mut self, value: G) -> Self
+    where
+        G: 'static + ClassNameGenerator,
+    {
+        self.class_name_generator = Rc::new(value);
+
+        self
+    }
+
+    /// Set the `nonce` attribute of all `<style />` tags created by this manager.
+    ///
+    /// This is required if the Content Security Policy of the page only allows inline styles with
+    /// a nonce.
+    pub fn nonce(mut self, value: Cow<'static, str>) -> Self {
+        self.nonce = Some(value);
+
+        self
+    }
+
+    /// Add an attribute to all `<style />` tags created by this manager (e.g.: `media` or
+    /// `data-*`).
+    ///
+    /// This method can be called multiple times to add multiple attributes. Setting an attribute
+    /// again replaces its value and setting `nonce` is the same as calling
+    /// [`nonce`](Self::nonce). Attribute names are compared case-insensitively. The `data-style`,
+    /// `data-style-conditional` and `data-style-rendered` attributes are reserved by stylist and
+    /// will be ignored.
+    ///
+    /// [`build`](Self::build) returns an error if the name is not a valid attribute name.
+    pub fn attribute(mut self, name: Cow<'static, str>, value: Cow<'static, str>) -> Self {
+        if name.eq_ignore_ascii_case("nonce") {
+            return self.nonce(value);
+        }
+
+        self.attributes
+            .retain(|(m, _)| !m.eq_ignore_ascii_case(&name));
+        self.attributes.push((name, value));
+
+        self
+    }
+
     /// Set the way how `<style />` tags are added to the container.
     ///
     /// When set to `false`, stylist will prepend the style tags to the container.
@@ -77,17 +153,98 @@ impl StyleManagerBuilder {
         self
     }
 
+    /// Set when styles are inserted into and removed from the DOM.
+    ///
+    /// Batching mounts avoids style recalculations when many styles are created at once (e.g.:
+    /// during the first render of an application). A batched style gets its class name
+    /// immediately, but it is only mounted once the manager is flushed.
+    ///
+    /// Default: [`MountMode::Immediate`]
+    pub fn mount_mode(mut self, value: MountMode) -> Self {
+        self.mount_mode = value;
+
+        self
+    }
+
+    /// Set whether styles are split into atomic styles.
+    ///
+    /// In atomic mode, each style attribute of a [`Style`](crate::Style) becomes a separate class
+    /// together with the at-rules (`@media`, `@supports`, `@container` and `@layer`) and the
+    /// selectors it is nested in. Attributes are deduplicated across styles, so the size of the
+    /// stylesheet grows with the number of distinct declarations instead of the number of styles.
+    /// The class name of a style becomes a space-separated list of classes.
+    ///
+    /// Content that can not be split (e.g.: `@keyframes` or nested blocks) is kept in one more
+    /// class of the style.
+    ///
+    /// An atomic class is unregistered together with the last registered style that uses it.
+    ///
+    /// Atomic classes nested in at-rules and the content that can not be split are always placed
+    /// after the other atomic classes, so they take precedence regardless of the order styles are
+    /// created in. Within a style, a later declaration of a property replaces an earlier one in
+    /// the same context unless the earlier one is `!important`.
+    ///
+    /// # Note
+    ///
+    /// Apart from that, atomic classes are ordered by the time they are mounted instead of the
+    /// order in a style. A shorthand property (e.g.: `margin`) and its longhand properties (e.g.:
+    /// `margin-top`) should not be mixed in the same context. Atomic styles can not be
+    /// [replaced](crate::Style::replace).
+    ///
+    /// Default: `false`
+    pub fn atomic(mut self, value: bool) -> Self {
+        self.atomic = value;
+
+        self
+    }
+
+    /// Set whether styles adopt `<style />` tags rendered on the server.
+    ///
+    /// When a style is mounted into a container that already contains a `<style />` tag of the
+    /// same style with the same content (e.g.: rendered with
+    /// [`StyleManager::render_style_tags`]), the existing tag is used instead of creating a new
+    /// one. Class names are only consistent with the server if the same [`ClassNameGenerator`] is
+    /// used and styles are created in the same order, so a [`CounterClassNameGenerator`] is
+    /// usually used on both sides. Tags that are not adopted are removed by
+    /// [`StyleManager::remove_rendered`].
+    ///
+    /// Default: `false`
+    pub fn hydrate(mut self, value: bool) -> Self {
+        self.hydrate = value;
+
+        self
+    }
+
     /// Build the [`StyleManager`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Attribute`](crate::Error::Attribute) if an attribute set with
+    /// [`attribute`](Self::attribute) has an invalid name.
     #[allow(unused_mut)]
     pub fn build(mut self) -> Result<StyleManager> {
+        if let Some((name, _)) = self
+            .attributes
+            .iter()
+            .find(|(m, _)| !is_valid_attribute_name(m))
+        {
+            return Err(crate::Error::Attribute {
+                reason: format!("`{}` is not a valid attribute name", name),
+            });
+        }
+
         #[cfg(target_arch = "wasm32")]
         if self.container.is_none() {
             use crate::arch::doc_head;
             self.container = Some(doc_head()?.into());
         }
 
+        let containers = self.container.iter().cloned().collect();
+
         Ok(StyleManager {
             inner: Rc::new(self),
+            containers: Rc::new(RefCell::new(containers)),
+            pending: Rc::default(),
         })
     }
 }
@@ -96,6 +253,10 @@ impl StyleManagerBuilder {
 #[derive(Debug, Clone)]
 pub struct StyleManager {
     inner: Rc<StyleManagerBuilder>,
+    // Containers styles are mounted into.
+    containers: Rc<RefCell<Containers<Node>>>,
+    // Mounts and unmounts queued when batching.
+    pending: Rc<RefCell<PendingOps<StyleContent, Element>>>,
 }
 
 impl StyleManager {
@@ -110,76 +271,626 @@ impl StyleManager {
     }
 
     /// The container [`Node`] for all style elements managed by this manager.
+    ///
+    /// This is the container this manager is built with. Use [`StyleManager::containers`] to get
+    /// all containers styles are mounted into.
     pub fn container(&self) -> Option<Node> {
         self.inner.container.clone()
     }
 
+    /// All containers styles of this manager are currently mounted into.
+    pub fn containers(&self) -> Vec<Node> {
+        self.containers.borrow().to_vec()
+    }
+
+    /// Adds a container that all styles of this manager are mounted into.
+    ///
+    /// All styles already registered are mounted into the container immediately and styles
+    /// created later will be mounted into all containers. Class names and the registry are shared
+    /// among containers.
+    ///
+    /// Containers are reference counted: adding a container that is already present increases its
+    /// count and the styles are only unmounted from it after [`StyleManager::remove_container`] is
+    /// called the same number of times. The container this manager is built with is counted once.
+    ///
+    /// If a style fails to mount, the styles mounted so far are removed from the container again
+    /// and the container is not added. Styles that are queued to be mounted are mounted into the
+    /// container when the queue is flushed.
+    pub fn add_container(&self, container: Node) -> Result<()> {
+        if !self.containers.borrow_mut().add(container.clone()) {
+            return Ok(());
+        }
+
+        let contents = self.mounted_contents();
+        let result = contents
+            .iter()
+            .try_for_each(|m| self.mount_into(m, &container));
+
+        if result.is_err() {
+            self.rollback_container(&contents, &container);
+        }
+
+        result
+    }
+
+    /// Removes a container added with [`StyleManager::add_container`].
+    ///
+    /// Styles are unmounted from the container once it has been removed as many times as it has
+    /// been added. Removing a container that is not present does nothing.
+    ///
+    /// Styles created while a manager has no container are registered and mounted once a
+    /// container is added.
+    pub fn remove_container(&self, container: &Node) -> Result<()> {
+        if !self.containers.borrow_mut().remove(container) {
+            return Ok(());
+        }
+
+        let contents = self.inner.registry.borrow().contents();
+        for content in contents.iter() {
+            self.unmount_from(content, container)?;
+        }
+
+        Ok(())
+    }
+
+    /// The [`MountMode`] of this manager.
+    pub fn mount_mode(&self) -> MountMode {
+        self.inner.mount_mode
+    }
+
+    /// Returns `true` if styles are split into atomic styles.
+    ///
+    /// See [`StyleManagerBuilder::atomic`].
+    pub fn is_atomic(&self) -> bool {
+        self.inner.atomic
+    }
+
+    /// Applies all queued mounts and unmounts.
+    ///
+    /// This only needs to be called when the manager is built with [`MountMode::Manual`] or with
+    /// [`MountMode::Render`] outside of yew components, other batching modes flush automatically.
+    /// Styles that have been dropped before the flush are
+    /// never mounted. All queued operations are applied even if some of them fail, in which case
+    /// the first error is returned.
+    pub fn flush(&self) -> Result<()> {
+        let mut result = Ok(());
+
+        loop {
+            // Operations can be queued while flushing, e.g.: when a style is dropped.
+            let ops = self.pending.borrow_mut().take();
+            if ops.is_empty() {
+                break;
+            }
+
+            let op_result = batch::apply(ops, &mut DomBackend { manager: self });
+            if result.is_ok() {
+                result = op_result;
+            }
+        }
+
+        result
+    }
+
+    /// The `nonce` attribute of `<style />` tags created by this manager.
+    pub fn nonce(&self) -> Option<Cow<'static, str>> {
+        self.inner.nonce.clone()
+    }
+
+    /// The additional attributes of `<style />` tags created by this manager.
+    pub fn attributes(&self) -> &[(Cow<'static, str>, Cow<'static, str>)] {
+        &self.inner.attributes
+    }
+
+    /// Returns the attributes of the `<style />` tag of a style in the order they are set.
+    pub(crate) fn style_attributes<'a>(
+        &'a self,
+        content: &'a StyleContent,
+    ) -> Vec<(&'a str, &'a str)> {
+        let mut attrs = vec![(STYLE_ATTR, &**content.id())];
+
+        // Marks the tags that have to stay after other atomic styles.
+        if content.bucket == StyleBucket::Conditional {
+            attrs.push((CONDITIONAL_ATTR, ""));
+        }
+
+        if let Some(ref m) = self.inner.nonce {
+            attrs.push(("nonce", m));
+        }
+
+        attrs.extend(
+            self.inner
+                .attributes
+                .iter()
+                .filter(|(name, _)| {
+                    !name.eq_ignore_ascii_case(STYLE_ATTR)
+                        && !name.eq_ignore_ascii_case(CONDITIONAL_ATTR)
+                        && !name.eq_ignore_ascii_case(RENDERED_ATTR)
+                })
+                .map(|(name, value)| (name.as_ref(), value.as_ref())),
+        );
+
+        attrs
+    }
+
+    /// Renders all styles registered with this manager as `<style />` tags in the order of
+    /// [`styles`](Self::styles).
+    ///
+    /// The tags carry the same attributes as the ones mounted in the browser and are marked with a
+    /// `data-style-rendered` attribute until a hydrating manager adopts them. This is intended to
+    /// be used for server-side rendering.
+    pub fn render_style_tags(&self) -> String {
+        let mut s = String::new();
+
+        for content in self.inner.registry.borrow().contents() {
+            s.push_str("<style");
+            for (name, value) in self.style_attributes(&content) {
+                s.push(' ');
+                s.push_str(name);
+                s.push_str("=\"");
+                s.push_str(&escape_html_attr(value));
+                s.push('"');
+            }
+            s.push(' ');
+            s.push_str(RENDERED_ATTR);
+            s.push_str("=\"\">");
+            s.push_str(&escape_style_text(content.get_style_str()));
+            s.push_str("</style>");
+        }
+
+        s
+    }
+
+    /// Generates a class name for a style using the [`ClassNameGenerator`] of this manager.
+    pub(crate) fn generate_class_name(&self, ctx: &ClassNameContext<'_>) -> StyleId {
+        StyleId(self.inner.class_name_generator.generate(ctx))
+    }
+
     /// Get the Registry instance.
     pub(crate) fn get_registry(&self) -> Rc<RefCell<StyleRegistry>> {
         self.inner.registry.clone()
     }
 
-    /// Mount the [`Style`](crate::Style) into the DOM tree.
+    /// Returns all styles currently registered with this manager in the order they are mounted.
+    ///
+    /// This includes both scoped and global styles. Styles are ordered by the time they were
+    /// registered, except that atomic classes nested in at-rules are placed after other atomic
+    /// classes. This is the order of [`render_style_tags`](Self::render_style_tags).
+    pub fn styles(&self) -> Vec<RegisteredStyle> {
+        self.inner
+            .registry
+            .borrow()
+            .contents()
+            .into_iter()
+            .map(RegisteredStyle::new)
+            .collect()
+    }
+
+    /// Returns the number of styles currently registered with this manager.
+    pub fn style_count(&self) -> usize {
+        self.inner.registry.borrow().len()
+    }
+
+    /// Finds a registered scoped [`Style`] by its class name.
+    ///
+    /// Returns `None` if no scoped style with this class name is registered with this manager.
+    pub fn get_by_class_name(&self, class_name: &str) -> Option<Style> {
+        self.inner
+            .registry
+            .borrow()
+            .get_by_id(class_name)
+            .filter(|m| m.kind == StyleKind::Scoped)
+            .map(Style::from_content)
+    }
+
+    /// Unregisters all styles from this manager.
+    ///
+    /// Like [`Style::unregister`], each style will be unmounted from the DOM after all its clones
+    /// are freed. Queued mounts and unmounts are applied first.
+    pub fn clear(&self) {
+        let _result = self.flush();
+
+        // The removed styles are dropped after the registry is released as dropping may unmount.
+        let _removed = self.inner.registry.borrow_mut().clear();
+    }
+
+    /// Unregisters all styles from this manager and unmounts them from the DOM immediately.
+    ///
+    /// This is useful when the part of the page that uses this manager is being torn down.
+    /// Classes of styles that are still held elsewhere will no longer be applied. Queued mounts
+    /// are cancelled and queued unmounts are applied.
+    pub fn unmount_all(&self) -> Result<()> {
+        self.pending.borrow_mut().cancel_mounts();
+        let mut result = self.flush();
+
+        let removed = self.inner.registry.borrow_mut().clear();
+
+        for content in removed.iter() {
+            let remove_result = Self::remove_elements(content.take_elements());
+            if result.is_ok() {
+                result = remove_result;
+            }
+        }
+
+        result
+    }
+
+    /// Removes the `<style />` tags rendered on the server that no style of this manager has
+    /// adopted.
+    ///
+    /// This should be called once hydration is complete, as the tags of styles the client does not
+    /// create would otherwise stay in the page. Queued mounts are applied first, so the styles
+    /// created during hydration can still adopt their tags. Tags of styles created later are
+    /// mounted again.
+    ///
+    /// Does nothing if the manager does not [`hydrate`](StyleManagerBuilder::hydrate).
+    pub fn remove_rendered(&self) -> Result<()> {
+        if !self.inner.hydrate {
+            return Ok(());
+        }
+
+        let mut result = self.flush();
+
+        for container in self.containers().iter() {
+            let remove_result = Self::remove_elements(Self::find_all_rendered(container));
+            if result.is_ok() {
+                result = remove_result;
+            }
+        }
+
+        result
+    }
+
+    /// Returns the registered styles that are not queued to be mounted.
+    ///
+    /// Queued styles are mounted into all containers when the queue is flushed.
+    fn mounted_contents(&self) -> Vec<Rc<StyleContent>> {
+        let pending = self.pending.borrow();
+        let mut contents = self.inner.registry.borrow().contents();
+        contents.retain(|m| !pending.is_mount_pending(m));
+
+        contents
+    }
+
+    /// Mount the [`Style`](crate::Style) into the DOM tree or queue it when batching.
+    pub(crate) fn mount(&self, content: &Rc<StyleContent>) -> Result<()> {
+        if !self.inner.mount_mode.is_batched() {
+            return self.mount_now(content);
+        }
+
+        self.pending.borrow_mut().push_mount(Rc::downgrade(content));
+        self.schedule_flush();
+
+        Ok(())
+    }
+
+    /// Unmount the [`Style`](crate::Style) from the DOM tree or queue it when batching.
+    pub(crate) fn unmount(&self, content: &StyleContent) -> Result<()> {
+        let elements = content.take_elements();
+
+        if !self.inner.mount_mode.is_batched() {
+            return Self::remove_elements(elements);
+        }
+
+        self.pending.borrow_mut().push_unmount(elements);
+        self.schedule_flush();
+
+        Ok(())
+    }
+
+    /// Replaces a style with a new style that has the same id.
+    ///
+    /// The mounted elements of the old style are updated in place. If the old style has not been
+    /// mounted yet, the new style is mounted instead.
+    pub(crate) fn replace(&self, old: &Rc<StyleContent>, new: &Rc<StyleContent>) -> Result<()> {
+        self.inner.registry.borrow_mut().replace(old, new.clone());
+
+        // The old style may still be queued to be mounted.
+        old.supersede();
+
+        let elements = old.take_elements();
+        if elements.is_empty() {
+            return self.mount(new);
+        }
+
+        for m in elements.iter() {
+            m.set_text_content(Some(new.get_style_str()));
+        }
+        new.set_elements(elements);
+
+        Ok(())
+    }
+
+    /// Schedules a flush according to the [`MountMode`] of this manager.
+    #[cfg(target_arch = "wasm32")]
+    fn schedule_flush(&self) {
+        use wasm_bindgen::closure::Closure;
+        use wasm_bindgen::JsCast;
+
+        use crate::arch::window;
+
+        let mode = self.inner.mount_mode;
+        if !matches!(mode, MountMode::Microtask | MountMode::AnimationFrame)
+            || !self.pending.borrow_mut().schedule()
+        {
+            return;
+        }
+
+        let mgr = self.clone();
+        let callback = Closure::once_into_js(move || {
+            let _result = mgr.flush();
+        });
+
+        let scheduled = window().and_then(|m| {
+            match mode {
+                MountMode::Microtask => m.queue_microtask(callback.unchecked_ref()),
+                _ => {
+                    m.request_animation_frame(callback.unchecked_ref())
+                        .map_err(|e| crate::Error::Web(Some(e)))?;
+                }
+            }
+
+            Ok(())
+        });
+
+        // Styles should not stay unmounted if no flush can be scheduled.
+        if scheduled.is_err() {
+            let _result = self.flush();
+        }
+    }
+
+    /// Mount the [`Style`](crate::Style) into all containers.
+    ///
+    /// A style is only registered if there is no container, it is mounted when a container is
+    /// added.
     #[cfg(target_arch = "wasm32")]
-    pub(crate) fn mount(&self, content: &StyleContent) -> Result<()> {
+    fn mount_now(&self, content: &StyleContent) -> Result<()> {
+        for container in self.containers().iter() {
+            self.mount_into(content, container)?;
+        }
+
+        Ok(())
+    }
+
+    /// Mount the [`Style`](crate::Style) into a container.
+    #[cfg(target_arch = "wasm32")]
+    fn mount_into(&self, content: &StyleContent, container: &Node) -> Result<()> {
+        use wasm_bindgen::JsCast;
+        use web_sys::Document;
+
         use crate::arch::document;
         use crate::Error;
 
-        let document = document()?;
-        let container = self.container().ok_or(Error::Web(None))?;
+        if !container.is_connected() {
+            return Err(Error::ContainerDetached);
+        }
+
+        // The container may belong to another document (e.g.: an `<iframe />`).
+        let document = match container.owner_document() {
+            Some(m) => m,
+            None => match container.dyn_ref::<Document>() {
+                Some(m) => m.clone(),
+                None => document()?,
+            },
+        };
+
+        if self.inner.hydrate {
+            if let Some(m) = Self::find_rendered(content, container) {
+                m.remove_attribute(RENDERED_ATTR)
+                    .map_err(|e| Error::Web(Some(e)))?;
+                content.push_element(m);
+                return Ok(());
+            }
+        }
 
-        (|| {
+        let style_element = (|| {
             let style_element = document.create_element("style")?;
-            style_element.set_attribute("data-style", content.id())?;
+            for (name, value) in self.style_attributes(content) {
+                style_element.set_attribute(name, value)?;
+            }
             style_element.set_text_content(Some(content.get_style_str()));
 
-            // Prepend element
-            if !self.inner.append {
-                if let Some(m) = container.first_child() {
-                    return m.insert_before(&style_element, Some(&m)).map(|_m| ());
+            let next = self.insertion_point(content, container);
+            container.insert_before(&style_element, next.as_ref())?;
+            Ok(style_element)
+        })()
+        .map_err(|e| Error::Web(Some(e)))?;
+
+        content.push_element(style_element);
+
+        Ok(())
+    }
+
+    /// Returns the node the `<style />` tag of a style is inserted before, `None` appends the tag.
+    #[cfg(target_arch = "wasm32")]
+    fn insertion_point(&self, content: &StyleContent, container: &Node) -> Option<Node> {
+        use wasm_bindgen::JsCast;
+
+        // Finds the first element in the container that matches the predicate.
+        let find = |f: fn(&Element) -> bool| {
+            let mut node = container.first_child();
+            while let Some(m) = node {
+                if m.dyn_ref::<Element>().map(f).unwrap_or(false) {
+                    return Some(m);
                 }
+
+                node = m.next_sibling();
             }
 
-            container.append_child(&style_element)?;
-            Ok(())
-        })()
-        .map_err(|e| Error::Web(Some(e)))
+            None
+        };
+
+        match (self.inner.atomic, content.bucket, self.inner.append) {
+            (false, _, true) | (true, StyleBucket::Conditional, true) => None,
+            (false, _, false) | (true, StyleBucket::Plain, false) => container.first_child(),
+            // Plain atomic styles stay before all conditional styles.
+            (true, StyleBucket::Plain, true) => find(|m| m.has_attribute(CONDITIONAL_ATTR)),
+            // Conditional atomic styles stay after the plain styles at the start of the container.
+            (true, StyleBucket::Conditional, false) => {
+                find(|m| !m.has_attribute(STYLE_ATTR) || m.has_attribute(CONDITIONAL_ATTR))
+            }
+        }
     }
 
-    /// Unmount the [`Style`](crate::Style) from the DOM tree.
+    /// Finds a `<style />` tag of a style rendered on the server in a container.
     #[cfg(target_arch = "wasm32")]
-    pub(crate) fn unmount(&self, id: &StyleId) -> Result<()> {
-        use crate::arch::document;
-        use crate::Error;
+    fn find_rendered(content: &StyleContent, container: &Node) -> Option<Element> {
+        use wasm_bindgen::JsCast;
 
-        let document = document()?;
-        (|| {
-            if let Some(m) = document.query_selector(&format!("style[data-style={}]", id))? {
-                if let Some(parent) = m.parent_element() {
-                    parent.remove_child(&m)?;
+        let mut node = container.first_child();
+        while let Some(m) = node {
+            if let Some(element) = m.dyn_ref::<Element>() {
+                // The content is escaped when it is rendered.
+                if element.tag_name().eq_ignore_ascii_case("style")
+                    && element.has_attribute(RENDERED_ATTR)
+                    && element.get_attribute(STYLE_ATTR).as_deref() == Some(&**content.id())
+                    && element.text_content().as_deref()
+                        == Some(&*escape_style_text(content.get_style_str()))
+                {
+                    return Some(element.clone());
                 }
             }
 
-            Ok(())
-        })()
-        .map_err(|e| Error::Web(Some(e)))
+            node = m.next_sibling();
+        }
+
+        None
+    }
+
+    /// Finds all `<style />` tags rendered on the server that have not been adopted in a
+    /// container.
+    #[cfg(target_arch = "wasm32")]
+    fn find_all_rendered(container: &Node) -> Vec<Element> {
+        use wasm_bindgen::JsCast;
+
+        let mut elements = Vec::new();
+        let mut node = container.first_child();
+        while let Some(m) = node {
+            if let Some(element) = m.dyn_ref::<Element>() {
+                if element.tag_name().eq_ignore_ascii_case("style")
+                    && element.has_attribute(RENDERED_ATTR)
+                {
+                    elements.push(element.clone());
+                }
+            }
+
+            node = m.next_sibling();
+        }
+
+        elements
+    }
+
+    /// Removes the elements of an unmounted [`Style`](crate::Style) from the DOM tree.
+    #[cfg(target_arch = "wasm32")]
+    fn remove_elements(elements: Vec<Element>) -> Result<()> {
+        use crate::Error;
+
+        // Elements are removed from wherever they are mounted, even if a container has been
+        // detached since.
+        for m in elements {
+            if let Some(parent) = m.parent_node() {
+                parent.remove_child(&m).map_err(|e| Error::Web(Some(e)))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes a container that failed to be added and the styles mounted into it.
+    #[cfg(target_arch = "wasm32")]
+    fn rollback_container(&self, contents: &[Rc<StyleContent>], container: &Node) {
+        self.containers.borrow_mut().remove_all(container);
+
+        for content in contents.iter() {
+            let _result = self.unmount_from(content, container);
+        }
+    }
+
+    /// Unmount the [`Style`](crate::Style) from a container.
+    #[cfg(target_arch = "wasm32")]
+    fn unmount_from(&self, content: &StyleContent, container: &Node) -> Result<()> {
+        use crate::Error;
+
+        for m in content.take_elements_in(container) {
+            container
+                .remove_child(&m)
+                .map_err(|e| Error::Web(Some(e)))?;
+        }
+
+        Ok(())
+    }
+
+    /// Schedules a flush according to the [`MountMode`] of this manager.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn schedule_flush(&self) {
+        // Does nothing on non-wasm targets, only `MountMode::Manual` queues operations there.
     }
 
     /// Mount the [`Style`] in to the DOM tree.
     #[cfg(not(target_arch = "wasm32"))]
     #[allow(unused_variables)]
-    pub(crate) fn mount(&self, content: &StyleContent) -> Result<()> {
+    fn mount_now(&self, content: &StyleContent) -> Result<()> {
         // Does nothing on non-wasm targets.
         Ok(())
     }
 
-    /// Unmount the [`Style`] from the DOM tree.
+    /// Mount the [`Style`] into a container.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[allow(unused_variables)]
+    fn mount_into(&self, content: &StyleContent, container: &Node) -> Result<()> {
+        // Does nothing on non-wasm targets.
+        Ok(())
+    }
+
+    /// Finds all `<style />` tags rendered on the server that have not been adopted in a
+    /// container.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[allow(unused_variables)]
+    fn find_all_rendered(container: &Node) -> Vec<Element> {
+        // There are no rendered tags on non-wasm targets.
+        Vec::new()
+    }
+
+    /// Removes the elements of an unmounted [`Style`] from the DOM tree.
     #[cfg(not(target_arch = "wasm32"))]
     #[allow(unused_variables)]
-    pub(crate) fn unmount(&self, id: &StyleId) -> Result<()> {
+    fn remove_elements(elements: Vec<Element>) -> Result<()> {
         // Does nothing on non-wasm targets.
         Ok(())
     }
+
+    /// Removes a container that failed to be added.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[allow(unused_variables)]
+    fn rollback_container(&self, contents: &[Rc<StyleContent>], container: &Node) {
+        // Does nothing on non-wasm targets, mounting never fails.
+    }
+
+    /// Unmount the [`Style`] from a container.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[allow(unused_variables)]
+    fn unmount_from(&self, content: &StyleContent, container: &Node) -> Result<()> {
+        // Does nothing on non-wasm targets.
+        Ok(())
+    }
+}
+
+/// Applies queued operations of a [`StyleManager`] to the DOM tree.
+struct DomBackend<'a> {
+    manager: &'a StyleManager,
+}
+
+impl Backend<StyleContent, Element> for DomBackend<'_> {
+    fn mount(&mut self, content: &StyleContent) -> Result<()> {
+        if content.is_superseded() {
+            return Ok(());
+        }
+
+        self.manager.mount_now(content)
+    }
+
+    fn unmount(&mut self, elements: Vec<Element>) -> Result<()> {
+        StyleManager::remove_elements(elements)
+    }
 }
 
 impl From<&Self> for StyleManager {
@@ -197,3 +908,366 @@ impl Default for StyleManager {
         MGR.with(|m| (*m).clone())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GlobalStyle, StyleSource};
+
+    fn new_manager() -> StyleManager {
+        StyleManager::builder()
+            .prefix("test-manager".into())
+            .build()
+            .expect("Failed to create manager.")
+    }
+
+    #[test]
+    fn test_styles() {
+        let mgr = new_manager();
+
+        let style = Style::new_with_manager("color: red;", &mgr).expect("Failed to create Style.");
+        let global_style = GlobalStyle::new_with_manager("color: blue;", &mgr)
+            .expect("Failed to create GlobalStyle.");
+
+        assert_eq!(mgr.style_count(), 2);
+
+        let styles = mgr.styles();
+        let scoped = styles
+            .iter()
+            .find(|m| !m.is_global())
+            .expect("Failed to find scoped style.");
+        assert_eq!(scoped.id(), style.id());
+        assert_eq!(scoped.prefix(), "test-manager");
+        assert_eq!(scoped.get_style_str(), style.get_style_str());
+        assert_eq!(scoped.byte_size(), style.get_style_str().len());
+
+        let global = styles
+            .iter()
+            .find(|m| m.is_global())
+            .expect("Failed to find global style.");
+        assert_eq!(global.id(), global_style.id());
+        assert_eq!(global.prefix(), "test-manager-global");
+    }
+
+    #[test]
+    fn test_styles_order() {
+        let mgr = new_manager();
+
+        let created: Vec<_> = ["color: red;", "color: blue;", "color: green;"]
+            .into_iter()
+            .map(|m| Style::new_with_manager(m, &mgr).expect("Failed to create Style."))
+            .collect();
+
+        let ids: Vec<_> = mgr.styles().iter().map(|m| m.id().clone()).collect();
+        assert_eq!(
+            ids,
+            created.iter().map(|m| m.id().clone()).collect::<Vec<_>>()
+        );
+
+        let tags = mgr.render_style_tags();
+        let positions: Vec<_> = ids
+            .iter()
+            .map(|m| {
+                tags.find(&format!("data-style=\"{}\"", m))
+                    .expect("Failed to find style tag.")
+            })
+            .collect();
+        assert!(positions.windows(2).all(|m| m[0] < m[1]));
+    }
+
+    #[test]
+    fn test_get_by_class_name() {
+        let mgr = new_manager();
+
+        let style = Style::new_with_manager("color: red;", &mgr).expect("Failed to create Style.");
+        let global_style = GlobalStyle::new_with_manager("color: blue;", &mgr)
+            .expect("Failed to create GlobalStyle.");
+
+        let found = mgr
+            .get_by_class_name(style.get_class_name())
+            .expect("Failed to find style.");
+        assert_eq!(found.id(), style.id());
+
+        assert!(mgr.get_by_class_name(global_style.id()).is_none());
+        assert!(mgr.get_by_class_name("not-a-registered-class").is_none());
+    }
+
+    #[test]
+    fn test_class_name_generator() {
+        let mgr = StyleManager::builder()
+            .prefix("test-generator".into())
+            .class_name_generator(DebugClassNameGenerator::new())
+            .build()
+            .expect("Failed to create manager.");
+
+        let src = StyleSource::try_from("color: red;")
+            .expect("Failed to parse style.")
+            .with_name("my_app::Button-primary");
+        let style = Style::new_with_manager(src, &mgr).expect("Failed to create Style.");
+        assert_eq!(style.get_class_name(), "Button-primary-0");
+
+        let style = Style::new_with_manager("color: blue;", &mgr).expect("Failed to create Style.");
+        assert_eq!(style.get_class_name(), "test-generator-1");
+    }
+
+    #[test]
+    fn test_render_style_tags() {
+        let mgr = StyleManager::builder()
+            .prefix("test-tags".into())
+            .nonce("ignored".into())
+            .attribute("media".into(), "print".into())
+            .attribute("Nonce".into(), "a\"b".into())
+            .attribute("MEDIA".into(), "screen".into())
+            .attribute("DATA-STYLE".into(), "ignored".into())
+            .attribute("Data-Style-Rendered".into(), "ignored".into())
+            .build()
+            .expect("Failed to create manager.");
+
+        let style = Style::new_with_manager("color: red;", &mgr).expect("Failed to create Style.");
+        let global_style = GlobalStyle::new_with_manager(r#"content: "</style>";"#, &mgr)
+            .expect("Failed to create GlobalStyle.");
+
+        assert_eq!(
+            mgr.render_style_tags(),
+            format!(
+                r#"<style data-style="{}" nonce="a&quot;b" MEDIA="screen" data-style-rendered="">{}</style><style data-style="{}" nonce="a&quot;b" MEDIA="screen" data-style-rendered="">:root {{
+    content: "<\/style>";
+}}
+</style>"#,
+                style.id(),
+                style.get_style_str(),
+                global_style.id(),
+            )
+        );
+    }
+
+    #[test]
+    fn test_invalid_attribute() {
+        let result = StyleManager::builder()
+            .attribute("onload=\"alert(1)\"".into(), "".into())
+            .build();
+
+        assert!(matches!(result, Err(crate::Error::Attribute { .. })));
+    }
+
+    #[test]
+    fn test_mount_mode() {
+        let mgr = new_manager();
+        let _style = Style::new_with_manager("color: red;", &mgr).expect("Failed to create Style.");
+        assert_eq!(mgr.pending.borrow().len(), 0);
+
+        let mgr = StyleManager::builder()
+            .prefix("test-batch".into())
+            .mount_mode(MountMode::Manual)
+            .build()
+            .expect("Failed to create manager.");
+
+        let style = Style::new_with_manager("color: red;", &mgr).expect("Failed to create Style.");
+        let dropped =
+            Style::new_with_manager("color: blue;", &mgr).expect("Failed to create Style.");
+        assert_eq!(mgr.pending.borrow().len(), 2);
+
+        dropped.unregister();
+        drop(dropped);
+
+        mgr.flush().expect("Failed to flush.");
+        assert_eq!(mgr.pending.borrow().len(), 0);
+        assert_eq!(mgr.style_count(), 1);
+        assert!(mgr.get_by_class_name(style.get_class_name()).is_some());
+    }
+
+    #[test]
+    fn test_clear_pending() {
+        let mgr = StyleManager::builder()
+            .prefix("test-clear-pending".into())
+            .mount_mode(MountMode::Manual)
+            .build()
+            .expect("Failed to create manager.");
+
+        let _style = Style::new_with_manager("color: red;", &mgr).expect("Failed to create Style.");
+        assert_eq!(mgr.pending.borrow().len(), 1);
+
+        mgr.clear();
+        assert_eq!(mgr.pending.borrow().len(), 0);
+
+        let _style = Style::new_with_manager("color: red;", &mgr).expect("Failed to create Style.");
+        assert_eq!(mgr.pending.borrow().len(), 1);
+
+        // Queued mounts are cancelled.
+        mgr.unmount_all().expect("Failed to unmount styles.");
+        assert_eq!(mgr.pending.borrow().len(), 0);
+
+        // Modes that are flushed by the browser mount immediately on non-wasm targets.
+        let mgr = StyleManager::builder()
+            .prefix("test-microtask".into())
+            .mount_mode(MountMode::Microtask)
+            .build()
+            .expect("Failed to create manager.");
+
+        let _style = Style::new_with_manager("color: red;", &mgr).expect("Failed to create Style.");
+        assert_eq!(mgr.pending.borrow().len(), 0);
+    }
+
+    #[test]
+    fn test_mounted_contents() {
+        let mgr = StyleManager::builder()
+            .prefix("test-mounted".into())
+            .mount_mode(MountMode::Manual)
+            .build()
+            .expect("Failed to create manager.");
+
+        // Queued styles are not mounted into containers added before the flush, the flush mounts
+        // them into all containers.
+        let _style = Style::new_with_manager("color: red;", &mgr).expect("Failed to create Style.");
+        assert_eq!(mgr.style_count(), 1);
+        assert!(mgr.mounted_contents().is_empty());
+
+        mgr.flush().expect("Failed to flush.");
+        assert_eq!(mgr.mounted_contents().len(), 1);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mgr = new_manager();
+
+        let style = Style::new_with_manager("color: red;", &mgr).expect("Failed to create Style.");
+        mgr.clear();
+
+        assert_eq!(mgr.style_count(), 0);
+        assert!(mgr.get_by_class_name(style.get_class_name()).is_none());
+
+        // A style with the same source is created anew after the registry has been cleared.
+        let new_style =
+            Style::new_with_manager("color: red;", &mgr).expect("Failed to create Style.");
+        assert_ne!(style.id(), new_style.id());
+
+        mgr.unmount_all().expect("Failed to unmount styles.");
+        assert_eq!(mgr.style_count(), 0);
+    }
+
+    #[test]
+    fn test_atomic() {
+        let mgr = StyleManager::builder()
+            .prefix("test-atomic".into())
+            .atomic(true)
+            .build()
+            .expect("Failed to create manager.");
+
+        let style_a = Style::new_with_manager("display: flex; gap: 8px;", &mgr)
+            .expect("Failed to create Style.");
+        let style_b = Style::new_with_manager("display: flex; color: red;", &mgr)
+            .expect("Failed to create Style.");
+
+        let classes_a: Vec<_> = style_a.get_class_name().split(' ').collect();
+        let classes_b: Vec<_> = style_b.get_class_name().split(' ').collect();
+        assert_eq!(classes_a.len(), 2);
+        assert_eq!(classes_b.len(), 2);
+
+        // `display: flex;` is shared by both styles.
+        assert_eq!(classes_a[0], classes_b[0]);
+        assert_ne!(classes_a[1], classes_b[1]);
+        assert_eq!(mgr.style_count(), 3);
+
+        assert_eq!(
+            style_a.get_style_str(),
+            format!(
+                ".{} {{\n    display: flex;\n}}\n.{} {{\n    gap: 8px;\n}}\n",
+                classes_a[0], classes_a[1]
+            )
+        );
+
+        // Creating the same style again is a lookup.
+        let style_c = Style::new_with_manager("display: flex; gap: 8px;", &mgr)
+            .expect("Failed to create Style.");
+        assert_eq!(style_a.get_class_name(), style_c.get_class_name());
+        assert_eq!(mgr.style_count(), 3);
+    }
+
+    #[test]
+    fn test_atomic_conditional_order() {
+        let mgr = StyleManager::builder()
+            .prefix("test-atomic-order".into())
+            .atomic(true)
+            .build()
+            .expect("Failed to create manager.");
+
+        let conditional =
+            Style::new_with_manager("@media (max-width: 600px) { color: blue; }", &mgr)
+                .expect("Failed to create Style.");
+        let style = Style::new_with_manager(
+            "color: red; @media (max-width: 600px) { color: blue; }",
+            &mgr,
+        )
+        .expect("Failed to create Style.");
+
+        // The conditional atom is shared and placed after the plain atom created later.
+        assert_eq!(
+            style.get_class_name(),
+            format!(
+                "{} {}",
+                mgr.styles()
+                    .iter()
+                    .find(|m| m.get_style_str().starts_with('.'))
+                    .expect("Failed to find plain atom.")
+                    .id(),
+                conditional.get_class_name()
+            )
+        );
+
+        let tags = mgr.render_style_tags();
+        let plain_pos = tags
+            .find("color: red;")
+            .expect("Failed to find plain atom.");
+        let conditional_pos = tags
+            .find("@media")
+            .expect("Failed to find conditional atom.");
+        assert!(plain_pos < conditional_pos);
+        assert!(tags.contains(&format!(
+            r#"<style data-style="{}" data-style-conditional="" data-style-rendered="">"#,
+            conditional.get_class_name()
+        )));
+    }
+
+    #[test]
+    fn test_atomic_unregister() {
+        let mgr = StyleManager::builder()
+            .prefix("test-atomic-unregister".into())
+            .atomic(true)
+            .build()
+            .expect("Failed to create manager.");
+
+        // The style has the same key as its only atom.
+        let style = Style::new_with_manager("color: red;", &mgr).expect("Failed to create Style.");
+        let other = Style::new_with_manager("color: red; gap: 4px;", &mgr)
+            .expect("Failed to create Style.");
+        assert_eq!(mgr.style_count(), 2);
+
+        style.unregister();
+        assert_eq!(mgr.style_count(), 2);
+        assert!(mgr.get_by_class_name(style.get_class_name()).is_some());
+        assert!(other.get_class_name().starts_with(style.get_class_name()));
+
+        // Atoms are unregistered once no registered style uses them.
+        other.unregister();
+        assert_eq!(mgr.style_count(), 0);
+    }
+
+    #[test]
+    fn test_atomic_replace() {
+        let mgr = StyleManager::builder()
+            .prefix("test-atomic-replace".into())
+            .atomic(true)
+            .build()
+            .expect("Failed to create manager.");
+
+        let mut style =
+            Style::new_with_manager("color: red;", &mgr).expect("Failed to create Style.");
+        let class_name = style.get_class_name().to_string();
+
+        assert!(matches!(
+            style.replace("color: blue;"),
+            Err(crate::Error::Replace { .. })
+        ));
+        assert_eq!(style.get_class_name(), class_name);
+    }
+}
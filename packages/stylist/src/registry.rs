@@ -2,8 +2,41 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use std::rc::Rc;
 
-use crate::ast::Sheet;
-use crate::style::StyleContent;
+use crate::ast::{Sheet, ToStyleStr};
+use crate::keyframes::to_keyframes_sheet;
+use crate::style::{StyleContent, StyleId};
+use crate::Result;
+
+/// The kind of a style.
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq)]
+pub(crate) enum StyleKind {
+    Scoped,
+    Global,
+    Keyframes,
+}
+
+impl StyleKind {
+    /// Renders the stylesheet of a style with the provided id.
+    pub fn render(&self, id: &StyleId, ast: &Sheet) -> Result<String> {
+        match self {
+            Self::Scoped => Ok(ast.to_style_str(Some(id))),
+            Self::Global => Ok(ast.to_style_str(None)),
+            Self::Keyframes => Ok(to_keyframes_sheet(id, ast)?.to_style_str(None)),
+        }
+    }
+}
+
+/// The part of the stylesheet of a manager a style is mounted in.
+///
+/// Styles in a later bucket are always mounted after styles in an earlier bucket, so they take
+/// precedence when they have the same specificity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub(crate) enum StyleBucket {
+    #[default]
+    Plain,
+    // Atomic styles nested in at-rules and the content of atomic styles that can not be split.
+    Conditional,
+}
 
 /// A [`StyleKey`].
 ///
@@ -11,7 +44,7 @@ use crate::style::StyleContent;
 /// registry.
 #[derive(Debug, Clone, PartialEq, Hash, Eq)]
 pub(crate) struct StyleKey {
-    pub is_global: bool,
+    pub kind: StyleKind,
     pub prefix: Cow<'static, str>,
     pub ast: Sheet,
 }
@@ -19,13 +52,20 @@ pub(crate) struct StyleKey {
 /// The style registry is a registry that keeps an instance of all styles for current manager.
 #[derive(Debug, Default)]
 pub(crate) struct StyleRegistry {
-    styles: HashMap<Rc<StyleKey>, Rc<StyleContent>>,
+    // Each style is stored with a sequence number to keep track of the registration order.
+    styles: HashMap<Rc<StyleKey>, (u64, Rc<StyleContent>)>,
+    next_seq: u64,
+    // Styles composed of atomic styles, they are not mounted themselves.
+    composites: HashMap<Rc<StyleKey>, Rc<StyleContent>>,
 }
 
 impl StyleRegistry {
     pub(crate) fn register(&mut self, content: Rc<StyleContent>) {
         let key = content.key();
-        if self.styles.insert(key, content).is_some() {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+
+        if self.styles.insert(key, (seq, content)).is_some() {
             panic!("A Style with this StyleKey has already been created.");
         }
     }
@@ -34,8 +74,150 @@ impl StyleRegistry {
         self.styles.remove(&key);
     }
 
+    /// Unregisters a style composed of atomic styles.
+    ///
+    /// Atomic styles that are not used by another registered composed style are unregistered as
+    /// well, even if one of them has the same key as the composed style.
+    pub(crate) fn unregister_composite(&mut self, key: Rc<StyleKey>) {
+        if let Some(m) = self.composites.remove(&key) {
+            self.release_atoms(&m);
+        }
+    }
+
+    /// Unregisters the atomic styles of an unregistered composed style that no registered composed
+    /// style uses.
+    fn release_atoms(&mut self, composite: &StyleContent) {
+        for atom in composite.atoms() {
+            let used = self
+                .composites
+                .values()
+                .any(|m| m.atoms().iter().any(|m| Rc::ptr_eq(m, atom)));
+
+            if !used {
+                self.unregister_content(atom);
+            }
+        }
+    }
+
+    /// Unregisters a style if it is still the style registered with its key.
+    pub(crate) fn unregister_content(&mut self, content: &Rc<StyleContent>) {
+        if content.is_composite() {
+            if matches!(self.composites.get(&content.key), Some(m) if Rc::ptr_eq(m, content)) {
+                self.composites.remove(&content.key);
+                self.release_atoms(content);
+            }
+        } else if matches!(self.styles.get(&content.key), Some((_, m)) if Rc::ptr_eq(m, content)) {
+            self.styles.remove(&content.key);
+        }
+    }
+
+    pub(crate) fn register_composite(&mut self, content: Rc<StyleContent>) {
+        self.composites.insert(content.key(), content);
+    }
+
+    pub(crate) fn get_composite(&self, key: &StyleKey) -> Option<Rc<StyleContent>> {
+        self.composites.get(key).cloned()
+    }
+
+    /// Replaces a registered content with a new content that has a different key.
+    ///
+    /// The new content takes the place of the old content in the registration order. Nothing is
+    /// registered if the old content is not registered or the new key is already taken.
+    pub(crate) fn replace(&mut self, old: &Rc<StyleContent>, new: Rc<StyleContent>) {
+        let seq = match self.styles.get(&old.key) {
+            Some((seq, m)) if Rc::ptr_eq(m, old) => *seq,
+            _ => return,
+        };
+        self.styles.remove(&old.key);
+
+        let key = new.key();
+        self.styles.entry(key).or_insert((seq, new));
+    }
+
     pub(crate) fn get(&self, key: &StyleKey) -> Option<Rc<StyleContent>> {
-        self.styles.get(key).cloned()
+        self.styles.get(key).map(|(_, m)| m.clone())
+    }
+
+    pub(crate) fn get_by_id(&self, id: &str) -> Option<Rc<StyleContent>> {
+        self.styles
+            .values()
+            .find(|(_, m)| &**m.id() == id)
+            .map(|(_, m)| m.clone())
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.styles.len()
+    }
+
+    /// Returns all registered styles in the order they are mounted.
+    ///
+    /// Styles are ordered by their [`StyleBucket`] and then by the order they were registered.
+    pub(crate) fn contents(&self) -> Vec<Rc<StyleContent>> {
+        let mut contents: Vec<_> = self.styles.values().collect();
+        contents.sort_by_key(|(seq, m)| (m.bucket, *seq));
+
+        contents.into_iter().map(|(_, m)| m.clone()).collect()
+    }
+
+    /// Removes all styles from the registry, returning the removed contents.
+    pub(crate) fn clear(&mut self) -> Vec<Rc<StyleContent>> {
+        self.styles
+            .drain()
+            .map(|(_, (_, m))| m)
+            .chain(self.composites.drain().map(|(_, m)| m))
+            .collect()
+    }
+}
+
+/// A snapshot of a style registered in a [`StyleManager`](crate::manager::StyleManager).
+///
+/// This is returned by [`StyleManager::styles`](crate::manager::StyleManager::styles) and is
+/// primarily intended for development tools and tests.
+///
+/// # Note
+///
+/// Holding a `RegisteredStyle` keeps the style alive in the same way as holding a
+/// [`Style`](crate::Style) would.
+#[derive(Debug, Clone)]
+pub struct RegisteredStyle {
+    inner: Rc<StyleContent>,
+}
+
+impl RegisteredStyle {
+    pub(crate) fn new(inner: Rc<StyleContent>) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the [`StyleId`] of the style.
+    ///
+    /// For scoped styles, this is also the class name.
+    pub fn id(&self) -> &StyleId {
+        self.inner.id()
+    }
+
+    /// Returns the prefix the style was created with.
+    pub fn prefix(&self) -> &str {
+        &self.inner.key.prefix
+    }
+
+    /// Returns `true` if the style is a [`GlobalStyle`](crate::GlobalStyle).
+    pub fn is_global(&self) -> bool {
+        self.inner.kind == StyleKind::Global
+    }
+
+    /// Returns `true` if the style is a [`Keyframes`](crate::Keyframes).
+    pub fn is_keyframes(&self) -> bool {
+        self.inner.kind == StyleKind::Keyframes
+    }
+
+    /// Returns the rendered stylesheet.
+    pub fn get_style_str(&self) -> &str {
+        self.inner.get_style_str()
+    }
+
+    /// Returns the size of the rendered stylesheet in bytes.
+    pub fn byte_size(&self) -> usize {
+        self.inner.get_style_str().len()
     }
 }
 
@@ -1,18 +1,22 @@
 use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
 use std::fmt;
 use std::ops::Deref;
 use std::rc::Rc;
 
 #[cfg(all(debug_assertions, feature = "parser"))]
 use stylist_core::ResultDisplay;
-
-use crate::ast::ToStyleStr;
-use crate::manager::StyleManager;
-use crate::registry::StyleKey;
+use web_sys::Element;
+#[cfg(target_arch = "wasm32")]
+use web_sys::Node;
+
+use crate::ast::Sheet;
+use crate::atomic;
+use crate::hold::HoldStyle;
+use crate::manager::{ClassNameContext, StyleManager};
+use crate::registry::{StyleBucket, StyleKey, StyleKind};
 use crate::{Result, StyleSource};
 
-use crate::utils::get_entropy;
-
 /// The Unique Identifier of a Style.
 ///
 /// This is primarily used by [`StyleManager`] to track the mounted instance of [`Style`].
@@ -34,8 +38,7 @@ impl fmt::Display for StyleId {
 
 #[derive(Debug)]
 pub(crate) struct StyleContent {
-    #[allow(dead_code)]
-    pub is_global: bool,
+    pub kind: StyleKind,
 
     pub id: StyleId,
 
@@ -44,9 +47,108 @@ pub(crate) struct StyleContent {
     pub style_str: String,
 
     pub manager: StyleManager,
+
+    pub bucket: StyleBucket,
+
+    // The `<style />` elements this style is mounted as, one per container of the manager.
+    elements: RefCell<Vec<Element>>,
+
+    // Set when the content has been replaced by a new one with the same id.
+    superseded: Cell<bool>,
+
+    // The number of claims on the registration of this style, see `StyleHold`.
+    holders: Cell<usize>,
+
+    // Set when the style has been handed out without a claim, it then stays registered.
+    pinned: Cell<bool>,
+
+    // The atomic styles this style is composed of in atomic mode, they are kept alive as long as
+    // this style is alive.
+    atoms: Option<Vec<Rc<StyleContent>>>,
 }
 
 impl StyleContent {
+    pub fn new(
+        kind: StyleKind,
+        id: StyleId,
+        key: Rc<StyleKey>,
+        style_str: String,
+        manager: StyleManager,
+    ) -> Self {
+        Self {
+            kind,
+            id,
+            key,
+            style_str,
+            manager,
+            bucket: StyleBucket::default(),
+            elements: RefCell::default(),
+            superseded: Cell::new(false),
+            holders: Cell::new(0),
+            pinned: Cell::new(false),
+            atoms: None,
+        }
+    }
+
+    /// Sets the atomic styles this style is composed of.
+    pub fn with_atoms(mut self, atoms: Vec<Rc<StyleContent>>) -> Self {
+        self.atoms = Some(atoms);
+
+        self
+    }
+
+    /// Sets the bucket this style is mounted in.
+    pub fn with_bucket(mut self, bucket: StyleBucket) -> Self {
+        self.bucket = bucket;
+
+        self
+    }
+
+    /// Returns `true` if this style is composed of atomic styles.
+    pub fn is_composite(&self) -> bool {
+        self.atoms.is_some()
+    }
+
+    /// Returns the atomic styles this style is composed of.
+    pub fn atoms(&self) -> &[Rc<StyleContent>] {
+        self.atoms.as_deref().unwrap_or_default()
+    }
+
+    /// Creates a content with the same id from a new stylesheet and replaces the current content
+    /// with it.
+    ///
+    /// Mounted elements are updated in place and moved to the new content.
+    pub fn replace(self: &Rc<Self>, ast: Sheet) -> Result<Rc<Self>> {
+        let key = StyleKey {
+            kind: self.kind,
+            prefix: self.key.prefix.clone(),
+            ast,
+        };
+
+        if key == *self.key {
+            return Ok(self.clone());
+        }
+
+        let id = self.id.clone();
+        let style_str = self.kind.render(&id, &key.ast)?;
+
+        // We parse the style str again in debug mode to ensure that interpolated values are
+        // not corrupting the stylesheet.
+        #[cfg(all(debug_assertions, feature = "parser"))]
+        style_str
+            .parse::<crate::ast::Sheet>()
+            .expect_display("debug: Stylist failed to parse the style with interpolated values");
+
+        let content = Rc::new(
+            Self::new(self.kind, id, Rc::new(key), style_str, self.manager.clone())
+                .with_bucket(self.bucket),
+        );
+        content.pinned.set(self.pinned.get());
+        self.manager.replace(self, &content)?;
+
+        Ok(content)
+    }
+
     pub fn id(&self) -> &StyleId {
         &self.id
     }
@@ -56,7 +158,68 @@ impl StyleContent {
     }
 
     pub fn unmount(&self) -> Result<()> {
-        self.manager().unmount(self.id())
+        self.manager().unmount(self)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn push_element(&self, element: Element) {
+        self.elements.borrow_mut().push(element);
+    }
+
+    pub fn set_elements(&self, elements: Vec<Element>) {
+        *self.elements.borrow_mut() = elements;
+    }
+
+    pub fn supersede(&self) {
+        self.superseded.set(true);
+    }
+
+    pub fn is_superseded(&self) -> bool {
+        self.superseded.get()
+    }
+
+    /// Returns the number of claims on the registration of this style.
+    #[cfg_attr(not(feature = "yew"), allow(dead_code))]
+    pub fn holders(&self) -> usize {
+        self.holders.get()
+    }
+
+    pub fn add_holder(&self) {
+        self.holders.set(self.holders.get() + 1);
+    }
+
+    /// Removes a holder, returns `true` if it was the last one.
+    pub fn remove_holder(&self) -> bool {
+        let holders = self.holders.get().saturating_sub(1);
+        self.holders.set(holders);
+
+        holders == 0
+    }
+
+    /// Keeps the style registered after the last claim on it is released.
+    pub fn pin(&self) {
+        self.pinned.set(true);
+    }
+
+    pub fn is_pinned(&self) -> bool {
+        self.pinned.get()
+    }
+
+    /// Takes all mounted elements.
+    pub fn take_elements(&self) -> Vec<Element> {
+        self.elements.take()
+    }
+
+    /// Takes the elements mounted in the provided container.
+    #[cfg(target_arch = "wasm32")]
+    pub fn take_elements_in(&self, container: &Node) -> Vec<Element> {
+        let mut elements = self.elements.borrow_mut();
+        let (taken, kept) = elements
+            .drain(..)
+            .partition(|m| m.parent_node().as_ref() == Some(container));
+        *elements = kept;
+
+        taken
     }
 
     pub fn key(&self) -> Rc<StyleKey> {
@@ -148,19 +311,114 @@ pub struct Style {
     inner: Rc<StyleContent>,
 }
 
+impl HoldStyle for Style {
+    fn content(&self) -> &Rc<StyleContent> {
+        &self.inner
+    }
+}
+
 impl Style {
+    pub(crate) fn from_content(inner: Rc<StyleContent>) -> Self {
+        Self { inner }
+    }
+
+    fn create_impl(
+        class_prefix: Cow<'static, str>,
+        css: StyleSource,
+        manager: StyleManager,
+    ) -> Result<Self> {
+        if manager.is_atomic() {
+            return Self::create_atomic(class_prefix, css, manager);
+        }
+
+        Self::create_scoped(class_prefix, css, manager, StyleBucket::Plain)
+    }
+
+    // Creates a style that is composed of atomic styles.
+    fn create_atomic(
+        class_prefix: Cow<'static, str>,
+        css: StyleSource,
+        manager: StyleManager,
+    ) -> Result<Self> {
+        let name = css.name().map(|m| m.to_string());
+        let key = StyleKey {
+            kind: StyleKind::Scoped,
+            prefix: class_prefix,
+            ast: css.into_sheet(),
+        };
+
+        let reg = manager.get_registry();
+        if let Some(m) = reg.borrow().get_composite(&key) {
+            return Ok(Style { inner: m });
+        }
+
+        let decomposed = atomic::decompose(&key.ast);
+        let atoms = decomposed
+            .plain
+            .into_iter()
+            .map(|m| (m, StyleBucket::Plain))
+            .chain(
+                decomposed
+                    .conditional
+                    .into_iter()
+                    .map(|m| (m, StyleBucket::Conditional)),
+            );
+
+        let mut contents = Vec::new();
+        for (atom, bucket) in atoms {
+            let style =
+                Self::create_scoped(key.prefix.clone(), atom.into(), manager.clone(), bucket)?;
+            contents.push(style.inner);
+        }
+
+        // Content that can not be split is specific to this style and is mounted after all atoms.
+        if !decomposed.rest.is_empty() {
+            let mut rest = StyleSource::from(decomposed.rest);
+            if let Some(m) = name {
+                rest = rest.with_name(m);
+            }
+
+            let style = Self::create_scoped(
+                key.prefix.clone(),
+                rest,
+                manager.clone(),
+                StyleBucket::Conditional,
+            )?;
+            contents.push(style.inner);
+        }
+
+        let id = StyleId(
+            contents
+                .iter()
+                .map(|m| &**m.id())
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+        let style_str = contents.iter().map(|m| m.get_style_str()).collect();
+
+        let content: Rc<_> =
+            StyleContent::new(StyleKind::Scoped, id, Rc::new(key), style_str, manager)
+                .with_atoms(contents)
+                .into();
+        reg.borrow_mut().register_composite(content.clone());
+
+        Ok(Self { inner: content })
+    }
+
     // The big method is monomorphic, so less code duplication and code bloat through generics
     // and inlining
-    fn create_impl(
+    fn create_scoped(
         class_prefix: Cow<'static, str>,
         css: StyleSource,
         manager: StyleManager,
+        bucket: StyleBucket,
     ) -> Result<Self> {
+        let name = css.name().map(|m| m.to_string());
         let css = css.into_sheet();
 
         // Creates the StyleKey, return from registry if already cached.
         let key = StyleKey {
-            is_global: false,
+            kind: StyleKind::Scoped,
             prefix: class_prefix,
             ast: css,
         };
@@ -172,9 +430,14 @@ impl Style {
             return Ok(Style { inner: m });
         }
 
-        let id = StyleId(format!("{}-{}", key.prefix, get_entropy()));
+        let id = manager.generate_class_name(&ClassNameContext {
+            prefix: &key.prefix,
+            name: name.as_deref(),
+            kind: StyleKind::Scoped,
+            registry: &reg,
+        });
 
-        let style_str = key.ast.to_style_str(Some(&id));
+        let style_str = StyleKind::Scoped.render(&id, &key.ast)?;
 
         // We parse the style str again in debug mode to ensure that interpolated values are
         // not corrupting the stylesheet.
@@ -184,14 +447,9 @@ impl Style {
             .expect_display("debug: Stylist failed to parse the style with interpolated values");
 
         let new_style = Self {
-            inner: StyleContent {
-                is_global: false,
-                id,
-                style_str,
-                manager,
-                key: Rc::new(key),
-            }
-            .into(),
+            inner: StyleContent::new(StyleKind::Scoped, id, Rc::new(key), style_str, manager)
+                .with_bucket(bucket)
+                .into(),
         };
 
         new_style.inner.manager().mount(&new_style.inner)?;
@@ -260,12 +518,32 @@ impl Style {
         crate::Error: From<Css::Error>,
         M: Into<StyleManager>,
     {
-        Self::create_impl(class_prefix.into(), css.try_into()?, manager.into())
+        let style = Self::create_impl(class_prefix.into(), css.try_into()?, manager.into())?;
+        // Styles created by users may be used without a claim, e.g.: as a class name.
+        style.inner.pin();
+
+        Ok(style)
+    }
+
+    /// Creates a style that is only kept registered while it is claimed with a
+    /// [`StyleHold`](crate::hold::StyleHold), unless it is also created elsewhere.
+    #[cfg_attr(
+        not(any(
+            feature = "yew",
+            feature = "leptos_integration",
+            feature = "dioxus_integration"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) fn new_unpinned(css: StyleSource, manager: &StyleManager) -> Result<Self> {
+        Self::create_impl(manager.prefix(), css, manager.clone())
     }
 
     /// Returns the class name for current style
     ///
-    /// You can add this class name to the element to apply the style.
+    /// You can add this class name to the element to apply the style. In
+    /// [atomic mode](crate::manager::StyleManagerBuilder::atomic), this is a space-separated list
+    /// of classes.
     ///
     /// # Examples
     ///
@@ -320,13 +598,66 @@ impl Style {
     pub fn unregister(&self) {
         let reg = self.inner.manager().get_registry();
         let mut reg = reg.borrow_mut();
-        reg.unregister(self.key());
+
+        if self.inner.is_composite() {
+            reg.unregister_composite(self.key());
+        } else {
+            reg.unregister(self.key());
+        }
     }
 
     /// Returns the [`StyleId`] for current style.
     pub fn id(&self) -> &StyleId {
         self.inner.id()
     }
+
+    /// Replaces the stylesheet of current style while keeping its class name.
+    ///
+    /// The mounted `<style />` element is updated in place and the registry is updated so that
+    /// the new stylesheet resolves to this style. This is useful for live theme editors and hot
+    /// reloading.
+    ///
+    /// # Note
+    ///
+    /// Styles created from the same source share the same instance. Other clones of this style
+    /// keep the old stylesheet, but the class name is styled by the new stylesheet. If a style with
+    /// the new stylesheet is already registered, the replaced style is not registered.
+    ///
+    /// # Errors
+    ///
+    /// In [atomic mode](crate::manager::StyleManagerBuilder::atomic), the classes of a style are
+    /// shared with other styles and can not be updated in place, so [`Error::Replace`] is returned.
+    ///
+    /// [`Error::Replace`]: crate::Error::Replace
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stylist::Style;
+    ///
+    /// let mut style = Style::create("my-editor", "color: red;")?;
+    /// let class_name = style.get_class_name().to_string();
+    ///
+    /// style.replace("color: blue;")?;
+    /// assert_eq!(style.get_class_name(), class_name);
+    /// # Ok::<(), stylist::Error>(())
+    /// ```
+    pub fn replace<Css>(&mut self, css: Css) -> Result<()>
+    where
+        Css: TryInto<StyleSource>,
+        crate::Error: From<Css::Error>,
+    {
+        // Atomic styles are shared, replacing them would change other styles.
+        if self.inner.is_composite() {
+            return Err(crate::Error::Replace {
+                reason: "styles of a manager in atomic mode can not be replaced".into(),
+            });
+        }
+
+        self.inner = self.inner.replace(css.try_into()?.into_sheet())?;
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -400,4 +731,30 @@ mod tests {
             )
         )
     }
+
+    #[test]
+    fn test_replace() {
+        let mut style =
+            Style::create("test-replace", "color: red;").expect("Failed to create Style.");
+        let old_style = style.clone();
+
+        style
+            .replace("color: blue;")
+            .expect("Failed to replace Style.");
+
+        assert_eq!(style.id(), old_style.id());
+        assert_eq!(
+            style.get_style_str(),
+            format!(".{} {{\n    color: blue;\n}}\n", style.get_class_name())
+        );
+
+        // The registry resolves the new stylesheet to the replaced style.
+        let same_style =
+            Style::create("test-replace", "color: blue;").expect("Failed to create Style.");
+        assert_eq!(same_style.id(), style.id());
+
+        let new_style =
+            Style::create("test-replace", "color: red;").expect("Failed to create Style.");
+        assert_ne!(new_style.id(), style.id());
+    }
 }
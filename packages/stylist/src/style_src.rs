@@ -1,6 +1,13 @@
+use std::borrow::Cow;
+
 use crate::ast::Sheet;
 use crate::manager::StyleManager;
-#[cfg(feature = "yew")]
+use crate::utils::escape_declaration_value;
+#[cfg(any(
+    feature = "yew",
+    feature = "leptos_integration",
+    feature = "dioxus_integration"
+))]
 use crate::Style;
 
 /// A struct that can be used as a source to create a [`Style`](crate::Style) or
@@ -20,38 +27,193 @@ use crate::Style;
 /// let rendered = html! {<div class={s.clone()} />};
 /// let global_rendered = html! {<Global css={s} />};
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct StyleSource {
     inner: Sheet,
 
     manager: Option<StyleManager>,
-    #[cfg(all(debug_assertions, feature = "debug_style_locations"))]
-    pub(crate) location: String,
+    name: Option<Cow<'static, str>>,
+    vars: Vec<(Cow<'static, str>, String)>,
 }
 
-impl StyleSource {
-    #[cfg(all(debug_assertions, feature = "debug_style_locations"))]
-    #[track_caller]
-    pub(crate) fn get_caller_location() -> String {
-        let caller_loc = std::panic::Location::caller();
-        // Who cares if this a valid css class name, it's debugging info
-        format!(
-            "{}:{}:{}",
-            caller_loc.file(),
-            caller_loc.line(),
-            caller_loc.column()
-        )
+// The name does not affect the style created from a source.
+impl PartialEq for StyleSource {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner && self.manager == other.manager && self.vars == other.vars
     }
+}
 
+impl StyleSource {
+    // Custom properties that have not been split off are declared by the stylesheet, so the
+    // stylesheet does not refer to undeclared properties.
     pub(crate) fn into_sheet(self) -> Sheet {
-        self.inner
+        use crate::ast::{Block, RuleBlockContent, ScopeContent, StyleAttribute};
+
+        if self.vars.is_empty() {
+            return self.inner;
+        }
+
+        let vars: Vec<RuleBlockContent> = self
+            .vars
+            .into_iter()
+            .map(|(key, value)| {
+                let value = escape_declaration_value(&value).into_owned();
+                StyleAttribute {
+                    key,
+                    value: vec![value.into()].into(),
+                }
+                .into()
+            })
+            .collect();
+
+        let mut scopes = vec![ScopeContent::Block(Block {
+            condition: Cow::Borrowed(&[]),
+            content: vars.into(),
+        })];
+        scopes.extend(self.inner.iter().cloned());
+
+        scopes.into()
     }
 
+    /// Returns the manager attached to this source.
     #[cfg(feature = "yew")]
-    pub(crate) fn into_style(mut self) -> Style {
+    pub(crate) fn manager(&self) -> Option<&StyleManager> {
+        self.manager.as_ref()
+    }
+
+    /// Returns the name attached to this source.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Attaches a name to this source.
+    ///
+    /// The name is passed to the [`ClassNameGenerator`](crate::manager::ClassNameGenerator) of the
+    /// manager to create readable class names (e.g.: with
+    /// [`DebugClassNameGenerator`](crate::manager::DebugClassNameGenerator)). It does not affect
+    /// whether two sources are equal or are considered to be the same style.
+    ///
+    /// [`css!`](crate::css) attaches the path of the calling module and
+    /// [`styled_component`](crate::yew::styled_component) attaches the name of the component.
+    pub fn with_name<N>(mut self, name: N) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+    {
+        self.name = Some(name.into());
+
+        self
+    }
+
+    /// Returns the values of CSS custom properties attached to this source.
+    ///
+    /// [`css!`](crate::css) attaches a custom property for each dynamic interpolation when
+    /// `#![css_vars]` is used.
+    pub fn vars(&self) -> &[(Cow<'static, str>, String)] {
+        &self.vars
+    }
+
+    /// Attaches the value of a CSS custom property to this source.
+    ///
+    /// Custom properties should be applied to the element separately, see
+    /// [`StyleSource::split_vars`]. A style created from a source that still carries custom
+    /// properties declares them itself.
+    pub fn with_var<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+        V: Into<String>,
+    {
+        self.vars.push((name.into(), value.into()));
+
+        self
+    }
+
+    /// Renders the attached custom properties as declarations for the `style` attribute of an
+    /// element.
+    ///
+    /// Values are escaped, so they cannot add other declarations to the attribute.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use stylist::css;
+    /// use yew::prelude::*;
+    ///
+    /// let width = format!("{}px", 100);
+    /// let s = css!(#![css_vars] width: ${width};);
+    ///
+    /// assert!(s.vars_style().ends_with(": 100px;"));
+    /// ```
+    pub fn vars_style(&self) -> String {
+        let mut s = String::new();
+
+        for (name, value) in self.vars.iter() {
+            if !s.is_empty() {
+                s.push(' ');
+            }
+            s.push_str(name);
+            s.push_str(": ");
+            s.push_str(&escape_declaration_value(value));
+            s.push(';');
+        }
+
+        s
+    }
+
+    /// Splits this source into a source without custom properties and the declarations of the
+    /// custom properties for the `style` attribute of an element.
+    ///
+    /// A source that carries custom properties can be used as a class or to create a style
+    /// directly, but the custom properties are then declared by the style and each distinct value
+    /// creates a new style, which gives no benefit over a source without `#![css_vars]`.
+    /// [`styled!`](crate::yew::styled) components and
+    /// [`use_style_with_vars`](crate::yew::use_style_with_vars) apply them to the `style`
+    /// attribute instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use stylist::css;
+    /// use yew::prelude::*;
+    ///
+    /// let width = format!("{}px", 100);
+    /// let (s, vars) = css!(#![css_vars] width: ${width};).split_vars();
+    ///
+    /// // Renders one class no matter which width is used.
+    /// let rendered = html! {<div class={s} style={vars} />};
+    /// ```
+    pub fn split_vars(mut self) -> (Self, String) {
+        let vars_style = self.vars_style();
+        self.vars.clear();
+
+        (self, vars_style)
+    }
+
+    #[cfg(any(feature = "yew", feature = "dioxus_integration"))]
+    pub(crate) fn into_style(self) -> Style {
         use stylist_core::ResultDisplay;
+        let (src, manager) = self.into_class_source();
+        Style::new_with_manager(src, manager).expect_display("Failed to create style")
+    }
+
+    /// Creates a style that is only kept registered while it is claimed, see
+    /// [`Style::new_unpinned`].
+    #[cfg(feature = "leptos_integration")]
+    pub(crate) fn into_unpinned_style(self) -> Style {
+        use stylist_core::ResultDisplay;
+        let (src, manager) = self.into_class_source();
+        Style::new_unpinned(src, &manager).expect_display("Failed to create style")
+    }
+
+    // Returns the source and the manager to create the style of a source used as a class with.
+    #[cfg(any(
+        feature = "yew",
+        feature = "leptos_integration",
+        feature = "dioxus_integration"
+    ))]
+    fn into_class_source(mut self) -> (Self, StyleManager) {
         let manager = self.manager.take().unwrap_or_default();
-        Style::new_with_manager(self, manager).expect_display("Failed to create style")
+
+        (self, manager)
     }
 
     #[doc(hidden)]
@@ -63,13 +225,12 @@ impl StyleSource {
 }
 
 impl From<Sheet> for StyleSource {
-    #[cfg_attr(all(debug_assertions, feature = "debug_style_locations"), track_caller)]
     fn from(sheet: Sheet) -> StyleSource {
         StyleSource {
             inner: sheet,
             manager: None,
-            #[cfg(all(debug_assertions, feature = "debug_style_locations"))]
-            location: Self::get_caller_location(),
+            name: None,
+            vars: Vec::new(),
         }
     }
 }
@@ -77,56 +238,90 @@ impl From<Sheet> for StyleSource {
 #[cfg(feature = "parser")]
 mod feat_parser {
     use super::*;
-    use std::borrow::Cow;
     use std::str::FromStr;
 
     impl TryFrom<String> for StyleSource {
         type Error = crate::Error;
-        #[cfg_attr(all(debug_assertions, feature = "debug_style_locations"), track_caller)]
         fn try_from(other: String) -> crate::Result<StyleSource> {
             let sheet = other.parse()?;
             Ok(StyleSource {
                 inner: sheet,
                 manager: None,
-                #[cfg(all(debug_assertions, feature = "debug_style_locations"))]
-                location: Self::get_caller_location(),
+                name: None,
+                vars: Vec::new(),
             })
         }
     }
 
     impl<'a> TryFrom<&'a str> for StyleSource {
         type Error = crate::Error;
-        #[cfg_attr(all(debug_assertions, feature = "debug_style_locations"), track_caller)]
         fn try_from(other: &'a str) -> crate::Result<StyleSource> {
             let sheet = other.parse()?;
             Ok(StyleSource {
                 inner: sheet,
                 manager: None,
-                #[cfg(all(debug_assertions, feature = "debug_style_locations"))]
-                location: Self::get_caller_location(),
+                name: None,
+                vars: Vec::new(),
             })
         }
     }
 
     impl<'a> TryFrom<Cow<'a, str>> for StyleSource {
         type Error = crate::Error;
-        #[cfg_attr(all(debug_assertions, feature = "debug_style_locations"), track_caller)]
         fn try_from(other: Cow<'a, str>) -> crate::Result<StyleSource> {
             let sheet = other.parse()?;
             Ok(StyleSource {
                 inner: sheet,
                 manager: None,
-                #[cfg(all(debug_assertions, feature = "debug_style_locations"))]
-                location: Self::get_caller_location(),
+                name: None,
+                vars: Vec::new(),
             })
         }
     }
 
     impl FromStr for StyleSource {
         type Err = crate::Error;
-        #[cfg_attr(all(debug_assertions, feature = "debug_style_locations"), track_caller)]
         fn from_str(s: &str) -> Result<Self, Self::Err> {
             s.try_into()
         }
     }
 }
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eq() {
+        let src = StyleSource::try_from("color: red;").unwrap();
+
+        assert_eq!(src.clone().with_name("a"), src.clone().with_name("b"));
+        assert_ne!(src.clone().with_var("--a", "1px"), src);
+        assert_ne!(src, StyleSource::try_from("color: blue;").unwrap());
+        assert_ne!(src.clone().with_manager(StyleManager::default()), src);
+    }
+
+    #[test]
+    fn test_vars_style() {
+        let src = StyleSource::try_from("width: var(--a);")
+            .unwrap()
+            .with_var("--a", "1px; background: red");
+
+        assert_eq!(src.vars_style(), "--a: 1px\\; background: red;");
+    }
+
+    #[test]
+    fn test_sheet_declares_vars() {
+        use crate::ast::ToStyleStr;
+
+        let src = StyleSource::try_from("width: var(--a);")
+            .unwrap()
+            .with_var("--a", "1px; background: red");
+
+        // The custom properties are declared by the style.
+        assert_eq!(
+            src.into_sheet().to_style_str(Some("a")),
+            ".a {\n    --a: 1px\\; background: red;\n}\n.a {\n    width: var(--a);\n}\n"
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 #[cfg(feature = "random")]
 fn get_rand_str() -> String {
     use std::iter::repeat_with;
@@ -25,10 +27,106 @@ pub(crate) fn get_entropy() -> String {
     entropy
 }
 
+/// Encodes a number in base 36 (`0-9a-z`).
+pub(crate) fn to_base36(mut n: u64) -> String {
+    const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+    let mut s = Vec::new();
+    loop {
+        s.push(DIGITS[(n % 36) as usize]);
+        n /= 36;
+
+        if n == 0 {
+            break;
+        }
+    }
+    s.reverse();
+
+    String::from_utf8(s).expect("base 36 digits are valid utf-8")
+}
+
+/// Escapes a value to be used in a double-quoted html attribute.
+pub(crate) fn escape_html_attr(s: &str) -> Cow<'_, str> {
+    if !s.contains(&['&', '"', '<', '>'][..]) {
+        return s.into();
+    }
+
+    s.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .into()
+}
+
+/// Returns `true` if a string is a valid attribute name in the HTML syntax.
+pub(crate) fn is_valid_attribute_name(s: &str) -> bool {
+    let is_noncharacter =
+        |c: char| matches!(c, '\u{fdd0}'..='\u{fdef}') || (c as u32) & 0xfffe == 0xfffe;
+
+    !s.is_empty()
+        && s.chars().all(|c| {
+            !c.is_control()
+                && !is_noncharacter(c)
+                && !matches!(c, ' ' | '"' | '\'' | '>' | '/' | '=')
+        })
+}
+
+/// Escapes a declaration value so it cannot end the declaration or the block early.
+///
+/// Backslashes are escaped as well, so they cannot cancel the escapes.
+pub(crate) fn escape_declaration_value(s: &str) -> Cow<'_, str> {
+    if !s.contains(&[';', '{', '}', '\\'][..]) {
+        return s.into();
+    }
+
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, ';' | '{' | '}' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped.into()
+}
+
+/// Escapes the content of a `<style />` tag so it cannot close the tag early.
+pub(crate) fn escape_style_text(s: &str) -> Cow<'_, str> {
+    if !s.contains("</") {
+        return s.into();
+    }
+
+    s.replace("</", "<\\/").into()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_attribute_name() {
+        assert!(is_valid_attribute_name("media"));
+        assert!(is_valid_attribute_name("data-ünicode"));
+        assert!(is_valid_attribute_name(":x"));
+
+        assert!(!is_valid_attribute_name(""));
+        assert!(!is_valid_attribute_name("a b"));
+        assert!(!is_valid_attribute_name("a\tb"));
+        assert!(!is_valid_attribute_name("a=b"));
+        assert!(!is_valid_attribute_name("a\"b"));
+        assert!(!is_valid_attribute_name("a>b"));
+        assert!(!is_valid_attribute_name("a/b"));
+        assert!(!is_valid_attribute_name("a\u{fffe}"));
+    }
+
+    #[test]
+    fn test_base36() {
+        assert_eq!(to_base36(0), "0");
+        assert_eq!(to_base36(35), "z");
+        assert_eq!(to_base36(36), "10");
+        assert_eq!(to_base36(u64::MAX), "3w5e11264sgsf");
+    }
+
     #[test]
     fn test_counter() {
         // As long as everytime it yields a different id, it will be fine.
@@ -36,4 +134,15 @@ mod tests {
         assert_ne!(get_next_style_id(), get_next_style_id());
         assert_ne!(get_next_style_id(), get_next_style_id());
     }
+
+    #[test]
+    fn test_declaration_value() {
+        assert_eq!(escape_declaration_value("100px"), "100px");
+        assert_eq!(
+            escape_declaration_value("red; background: url(x)"),
+            "red\\; background: url(x)"
+        );
+        assert_eq!(escape_declaration_value("} .a {"), "\\} .a \\{");
+        assert_eq!(escape_declaration_value("a\\;"), "a\\\\\\;");
+    }
 }
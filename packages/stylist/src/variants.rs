@@ -0,0 +1,484 @@
+//! Style variants for component-level variant selection.
+//!
+//! Components often pick one of several styles depending on their props (e.g.: the size or the
+//! intent of a button). [`Variants`] composes a base style, a style for each option of each variant
+//! dimension and compound styles that apply to specific combinations of options. The styles of
+//! all combinations are registered when variants are built, so selecting a style during rendering
+//! does not need to create, parse or render a stylesheet.
+//!
+//! Usually, variants are declared with [`variants!`](crate::variants!) which generates a typed
+//! struct, an enum for each dimension and a props struct to select a combination.
+//!
+//! Only the stylesheets of sources are composed, custom properties created with `#![css_vars]` are
+//! not carried over to the styles of combinations.
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use crate::ast::{ScopeContent, Sheet};
+use crate::manager::StyleManager;
+use crate::{Error, Result, Style, StyleSource};
+
+#[derive(Debug, Clone)]
+struct Dimension {
+    name: Cow<'static, str>,
+    options: Vec<(Cow<'static, str>, Sheet)>,
+}
+
+#[derive(Debug, Clone)]
+struct Compound {
+    selection: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    sheet: Sheet,
+}
+
+/// The builder of [`Variants`].
+///
+/// Create one with [`Variants::builder`].
+#[derive(Debug, Clone, Default)]
+pub struct VariantsBuilder {
+    base: Option<StyleSource>,
+    dimensions: Vec<Dimension>,
+    compounds: Vec<Compound>,
+    manager: Option<StyleManager>,
+}
+
+impl VariantsBuilder {
+    /// Set the style that is applied to all combinations.
+    pub fn base(mut self, css: StyleSource) -> Self {
+        self.base = Some(css);
+
+        self
+    }
+
+    /// Add an option to a variant dimension.
+    ///
+    /// A dimension is created when its first option is added. The first option of a dimension is
+    /// used when a selection does not mention the dimension.
+    pub fn variant<D, O>(mut self, dimension: D, option: O, css: StyleSource) -> Self
+    where
+        D: Into<Cow<'static, str>>,
+        O: Into<Cow<'static, str>>,
+    {
+        let dimension = dimension.into();
+        let option = (option.into(), css.into_sheet());
+
+        match self.dimensions.iter_mut().find(|m| m.name == dimension) {
+            Some(m) => m.options.push(option),
+            None => self.dimensions.push(Dimension {
+                name: dimension,
+                options: vec![option],
+            }),
+        }
+
+        self
+    }
+
+    /// Add a style that is applied when all `(dimension, option)` pairs of a selection match.
+    ///
+    /// Compound styles are applied after the styles of variants in the order they are added.
+    pub fn compound<D, O, I>(mut self, selection: I, css: StyleSource) -> Self
+    where
+        D: Into<Cow<'static, str>>,
+        O: Into<Cow<'static, str>>,
+        I: IntoIterator<Item = (D, O)>,
+    {
+        self.compounds.push(Compound {
+            selection: selection
+                .into_iter()
+                .map(|(d, o)| (d.into(), o.into()))
+                .collect(),
+            sheet: css.into_sheet(),
+        });
+
+        self
+    }
+
+    /// Set the manager used to register the styles.
+    pub fn manager<M>(mut self, manager: M) -> Self
+    where
+        M: Into<StyleManager>,
+    {
+        self.manager = Some(manager.into());
+
+        self
+    }
+
+    /// Registers a style for every combination of options and creates the [`Variants`].
+    ///
+    /// Returns [`Error::Variants`] if a compound variant refers to a dimension or an option that
+    /// has not been declared.
+    pub fn build(self) -> Result<Variants> {
+        let Self {
+            base,
+            dimensions,
+            compounds,
+            manager,
+        } = self;
+
+        let manager = manager.unwrap_or_default();
+        let name = base.as_ref().and_then(|m| m.name()).map(|m| m.to_string());
+        let base = base.map(|m| m.into_sheet()).unwrap_or_default();
+
+        // Resolves compound variants to option indices.
+        let mut resolved_compounds = Vec::with_capacity(compounds.len());
+        for compound in compounds.iter() {
+            let mut selection = Vec::with_capacity(compound.selection.len());
+            for (dimension, option) in compound.selection.iter() {
+                let (dim_index, dim) = dimensions
+                    .iter()
+                    .enumerate()
+                    .find(|(_, m)| &m.name == dimension)
+                    .ok_or_else(|| Error::Variants {
+                        reason: format!("unknown variant dimension `{}`", dimension),
+                    })?;
+                let opt_index = dim
+                    .options
+                    .iter()
+                    .position(|(m, _)| m == option)
+                    .ok_or_else(|| Error::Variants {
+                        reason: format!("unknown option `{}` of variant `{}`", option, dimension),
+                    })?;
+
+                selection.push((dim_index, opt_index));
+            }
+
+            resolved_compounds.push((selection, &compound.sheet));
+        }
+
+        let len = dimensions.iter().map(|m| m.options.len()).product();
+        let mut styles = Vec::with_capacity(len);
+        let mut selection = vec![0; dimensions.len()];
+
+        for _ in 0..len {
+            let mut content = Vec::new();
+            push_sheet(&mut content, &base);
+
+            for (dim, opt_index) in dimensions.iter().zip(selection.iter()) {
+                push_sheet(&mut content, &dim.options[*opt_index].1);
+            }
+
+            for (compound_selection, sheet) in resolved_compounds.iter() {
+                if compound_selection.iter().all(|(d, o)| selection[*d] == *o) {
+                    push_sheet(&mut content, sheet);
+                }
+            }
+
+            let mut css = StyleSource::from(Sheet::from(content));
+            if let Some(ref m) = name {
+                css = css.with_name(m.clone());
+            }
+
+            styles.push(Style::new_with_manager(css, manager.clone())?);
+
+            // Advances to the next combination, the last dimension changes the fastest.
+            for (index, dim) in selection.iter_mut().zip(dimensions.iter()).rev() {
+                *index += 1;
+                if *index < dim.options.len() {
+                    break;
+                }
+                *index = 0;
+            }
+        }
+
+        Ok(Variants {
+            inner: Rc::new(VariantsInner { dimensions, styles }),
+        })
+    }
+}
+
+/// Appends the content of a sheet.
+///
+/// Dangling attributes are merged into the previous block of dangling attributes if they are
+/// adjacent, so a combination that only has dangling attributes renders as a single block.
+fn push_sheet(content: &mut Vec<ScopeContent>, sheet: &Sheet) {
+    for scope in sheet.iter() {
+        if let (Some(ScopeContent::Block(last)), ScopeContent::Block(block)) =
+            (content.last_mut(), scope)
+        {
+            if last.condition.is_empty() && block.condition.is_empty() {
+                last.content.to_mut().extend(block.content.iter().cloned());
+                continue;
+            }
+        }
+
+        content.push(scope.clone());
+    }
+}
+
+#[derive(Debug)]
+struct VariantsInner {
+    dimensions: Vec<Dimension>,
+    styles: Vec<Style>,
+}
+
+/// A set of styles for every combination of variant options.
+///
+/// # Example
+///
+/// ```
+/// use stylist::css;
+/// use stylist::variants::Variants;
+///
+/// let variants = Variants::builder()
+///     .base(css!(border: none;))
+///     .variant("size", "small", css!(padding: 4px;))
+///     .variant("size", "large", css!(padding: 16px;))
+///     .variant("intent", "primary", css!(color: blue;))
+///     .variant("intent", "danger", css!(color: red;))
+///     .compound([("size", "large"), ("intent", "danger")], css!(font-weight: bold;))
+///     .build()?;
+///
+/// let style = variants.get(&[("size", "large"), ("intent", "danger")]).unwrap();
+/// # Ok::<(), stylist::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Variants {
+    inner: Rc<VariantsInner>,
+}
+
+impl Variants {
+    /// Creates a builder of variants.
+    pub fn builder() -> VariantsBuilder {
+        VariantsBuilder::default()
+    }
+
+    /// Returns the style of a combination selected by `(dimension, option)` pairs.
+    ///
+    /// Dimensions that are not mentioned use their first option. Returns `None` if the selection
+    /// refers to a dimension or an option that has not been declared.
+    pub fn get(&self, selection: &[(&str, &str)]) -> Option<Style> {
+        let mut indices = vec![0; self.inner.dimensions.len()];
+
+        for (dimension, option) in selection.iter() {
+            let dim_index = self
+                .inner
+                .dimensions
+                .iter()
+                .position(|m| m.name == *dimension)?;
+            indices[dim_index] = self.inner.dimensions[dim_index]
+                .options
+                .iter()
+                .position(|(m, _)| m == option)?;
+        }
+
+        self.get_by_index(&indices)
+    }
+
+    /// Returns the style of a combination selected by the index of the option of each dimension.
+    ///
+    /// Dimensions and options are indexed in the order they are added to the builder.
+    pub fn get_by_index(&self, indices: &[usize]) -> Option<Style> {
+        if indices.len() != self.inner.dimensions.len() {
+            return None;
+        }
+
+        let mut index = 0;
+        for (opt_index, dim) in indices.iter().zip(self.inner.dimensions.iter()) {
+            if *opt_index >= dim.options.len() {
+                return None;
+            }
+            index = index * dim.options.len() + opt_index;
+        }
+
+        self.inner.styles.get(index).cloned()
+    }
+
+    /// Returns the styles of all combinations.
+    pub fn styles(&self) -> &[Style] {
+        &self.inner.styles
+    }
+}
+
+/// Declares typed style variants.
+///
+/// This macro generates a struct wrapping [`Variants`], an enum for each variant dimension and a
+/// props struct with a field for each dimension. The first option of each dimension is the
+/// default. `.class(&props)` returns the [`Style`] of the selected combination, which can be used
+/// as classes of an element.
+///
+/// Compound variants are optional and are applied after the styles of variants.
+///
+/// # Example
+///
+/// ```
+/// use stylist::{css, variants};
+///
+/// variants! {
+///     pub struct ButtonStyle(ButtonProps) {
+///         base: css!(border: none;),
+///         variants: {
+///             size: ButtonSize {
+///                 Small => css!(padding: 4px;),
+///                 Large => css!(padding: 16px;),
+///             },
+///             intent: ButtonIntent {
+///                 Primary => css!(color: blue;),
+///                 Danger => css!(color: red;),
+///             },
+///         },
+///         compounds: [
+///             { size: Large, intent: Danger } => css!(font-weight: bold;),
+///         ],
+///     }
+/// }
+///
+/// let button_style = ButtonStyle::new()?;
+/// let style = button_style.class(&ButtonProps {
+///     size: ButtonSize::Large,
+///     ..Default::default()
+/// });
+/// # Ok::<(), stylist::Error>(())
+/// ```
+#[macro_export]
+macro_rules! variants {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident($props:ident) {
+            base: $base:expr,
+            variants: {
+                $(
+                    $dim:ident: $dim_ty:ident {
+                        $($opt:ident => $opt_css:expr),+ $(,)?
+                    }
+                ),+ $(,)?
+            }
+            $(,
+                compounds: [
+                    $(
+                        { $($c_dim:ident: $c_opt:ident),+ $(,)? } => $c_css:expr
+                    ),* $(,)?
+                ]
+            )?
+            $(,)?
+        }
+    ) => {
+        $(
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            $vis enum $dim_ty {
+                $($opt),+
+            }
+
+            impl ::std::default::Default for $dim_ty {
+                fn default() -> Self {
+                    [$(Self::$opt),+][0]
+                }
+            }
+        )+
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        $vis struct $props {
+            $(pub $dim: $dim_ty),+
+        }
+
+        $(#[$meta])*
+        #[derive(Debug, Clone)]
+        $vis struct $name {
+            inner: $crate::variants::Variants,
+        }
+
+        impl $name {
+            /// Registers the styles of all combinations.
+            $vis fn new() -> $crate::Result<Self> {
+                Self::new_with_manager($crate::manager::StyleManager::default())
+            }
+
+            /// Registers the styles of all combinations using a custom manager.
+            $vis fn new_with_manager<M>(manager: M) -> $crate::Result<Self>
+            where
+                M: ::std::convert::Into<$crate::manager::StyleManager>,
+            {
+                let inner = $crate::variants::Variants::builder()
+                    .manager(manager)
+                    .base($base)
+                    $($(
+                        .variant(stringify!($dim), stringify!($opt), $opt_css)
+                    )+)+
+                    $($(
+                        .compound([$((stringify!($c_dim), stringify!($c_opt))),+], $c_css)
+                    )*)?
+                    .build()?;
+
+                Ok(Self { inner })
+            }
+
+            /// Returns the style of the combination selected by props.
+            $vis fn class(&self, props: &$props) -> $crate::Style {
+                self.inner
+                    .get_by_index(&[$(props.$dim as usize),+])
+                    .expect("all combinations are registered")
+            }
+
+            /// Returns the underlying variants.
+            $vis fn variants(&self) -> &$crate::variants::Variants {
+                &self.inner
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sheet(s: &str) -> StyleSource {
+        s.try_into().expect("Failed to parse style.")
+    }
+
+    #[test]
+    fn test_combinations() {
+        let variants = Variants::builder()
+            .base(sheet("display: block;"))
+            .variant("size", "small", sheet("padding: 4px;"))
+            .variant("size", "large", sheet("padding: 16px;"))
+            .variant("intent", "primary", sheet("color: blue;"))
+            .variant("intent", "danger", sheet("color: red;"))
+            .compound(
+                [("size", "large"), ("intent", "danger")],
+                sheet("font-weight: bold;"),
+            )
+            .build()
+            .expect("Failed to build variants.");
+
+        assert_eq!(variants.styles().len(), 4);
+
+        let style = variants.get(&[]).expect("Failed to get style.");
+        assert_eq!(
+            style.get_style_str(),
+            format!(
+                ".{name} {{\n    display: block;\n    padding: 4px;\n    color: blue;\n}}\n",
+                name = style.get_class_name()
+            )
+        );
+
+        let style = variants
+            .get(&[("intent", "danger"), ("size", "large")])
+            .expect("Failed to get style.");
+        assert_eq!(
+            style.get_style_str(),
+            format!(
+                ".{name} {{\n    display: block;\n    padding: 16px;\n    color: red;\n    font-weight: bold;\n}}\n",
+                name = style.get_class_name()
+            )
+        );
+        assert_eq!(
+            variants
+                .get_by_index(&[1, 1])
+                .map(|m| m.get_class_name().to_string()),
+            Some(style.get_class_name().to_string())
+        );
+
+        assert!(variants.get(&[("size", "medium")]).is_none());
+        assert!(variants.get_by_index(&[2, 0]).is_none());
+        assert!(variants.get_by_index(&[0]).is_none());
+    }
+
+    #[test]
+    fn test_unknown_compound() {
+        let result = Variants::builder()
+            .variant("size", "small", sheet("padding: 4px;"))
+            .compound([("size", "large")], sheet("font-weight: bold;"))
+            .build();
+
+        assert!(matches!(result, Err(Error::Variants { .. })));
+    }
+}
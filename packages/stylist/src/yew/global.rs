@@ -1,8 +1,7 @@
 use yew::prelude::*;
 
-use crate::manager::StyleManager;
-use crate::{GlobalStyle, StyleSource};
-use stylist_core::ResultDisplay;
+use super::use_global_style;
+use crate::StyleSource;
 
 /// The properties for [`Global`] Component, please see its documentation for usage.
 #[derive(Properties, Clone, Debug, PartialEq)]
@@ -12,6 +11,9 @@ pub struct GlobalProps {
 
 /// A Global Style that will be applied to `<html />` tag, inspired by [emotion](https://emotion.sh).
 ///
+/// The style is applied during rendering (see [`use_global_style`]) and removed when the
+/// component is unmounted.
+///
 /// The `css` attribute accepts type that implements
 /// [`IntoPropValue<StyleSource>`](yew::html::IntoPropValue) and
 /// panics if the string failed to be parsed into a stylesheet.
@@ -43,30 +45,9 @@ pub struct GlobalProps {
 /// }
 /// ```
 
-
 #[function_component(Global)]
 pub fn global(props: &GlobalProps) -> Html {
-    let mgr = use_context::<StyleManager>().unwrap_or_default();
-
-    #[derive(Debug, PartialEq)]
-    struct GlobalDependents {
-        manager: StyleManager,
-        css: StyleSource,
-    }
-
-    use_effect_with_deps(
-        |deps| {
-            let global_style =
-                GlobalStyle::new_with_manager(deps.css.clone(), deps.manager.clone())
-                    .expect_display("Failed to create style.");
-
-            move || global_style.unregister()
-        },
-        GlobalDependents {
-            manager: mgr,
-            css: props.css.clone(),
-        },
-    );
+    use_global_style(props.css.clone());
 
     Html::default()
 }
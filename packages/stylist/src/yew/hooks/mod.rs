@@ -1,11 +1,22 @@
+mod use_global_style;
+mod use_held_style;
 #[cfg(feature = "yew_use_media_query")]
 mod use_media_query;
 
 #[cfg(feature = "yew_use_style")]
 mod use_style;
+mod use_style_manager;
 
 #[cfg(feature = "yew_use_media_query")]
-pub use use_media_query::use_media_query;
+pub use use_media_query::{
+    use_breakpoint, use_media_queries, use_media_query, use_media_query_with_fallback,
+};
 
+pub use use_global_style::use_global_style;
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub use use_held_style::use_styled_source;
+pub(crate) use use_held_style::{use_claim_style, use_held_style};
 #[cfg(feature = "yew_use_style")]
-pub use use_style::use_style;
+pub use use_style::{use_style, use_style_with_vars};
+pub use use_style_manager::use_style_manager;
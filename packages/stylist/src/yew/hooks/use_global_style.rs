@@ -0,0 +1,46 @@
+use yew::prelude::*;
+
+use stylist_core::ResultDisplay;
+
+use super::{use_held_style, use_style_manager};
+use crate::manager::StyleManager;
+use crate::{GlobalStyle, StyleSource};
+
+fn create_global_style(css: &StyleSource, mgr: &StyleManager) -> GlobalStyle {
+    GlobalStyle::new_unpinned(css.clone(), mgr).expect_display("failed to create style")
+}
+
+/// A hook to create a [`GlobalStyle`] that is applied while the component is mounted.
+///
+/// The style is created during rendering, so it is applied before the first paint and is
+/// included in the styles rendered on the server. When the css or the [`StyleManager`] changes, the
+/// previous style is unregistered and the new style takes its place. The style is unregistered
+/// when the last component that uses it is unmounted.
+///
+/// # Example
+///
+/// ```
+/// use stylist::yew::use_global_style;
+/// use yew::prelude::*;
+///
+/// #[function_component(Comp)]
+/// fn comp() -> Html {
+///     use_global_style("body { margin: 0; }");
+///
+///     html! {<div>{"Hello world!"}</div>}
+/// }
+/// ```
+#[hook]
+pub fn use_global_style<Css>(css: Css) -> GlobalStyle
+where
+    Css: TryInto<StyleSource>,
+    crate::Error: From<Css::Error>,
+{
+    let mgr = use_style_manager();
+    let css: StyleSource = css
+        .try_into()
+        .map_err(crate::Error::from)
+        .expect_display("failed to create style");
+
+    use_held_style((css, mgr), |(css, mgr)| create_global_style(css, mgr))
+}
@@ -0,0 +1,125 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+#[cfg(feature = "macros")]
+use stylist_core::ResultDisplay;
+
+#[cfg(feature = "macros")]
+use super::use_style_manager;
+use crate::hold::HoldStyle;
+#[cfg(feature = "macros")]
+use crate::manager::StyleManager;
+#[cfg(feature = "macros")]
+use crate::{Style, StyleSource};
+
+// Compares styles by their contents, so the effect is only rerun when the style changes.
+#[derive(Debug)]
+struct HeldStyle<S>(S);
+
+impl<S> PartialEq for HeldStyle<S>
+where
+    S: HoldStyle,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(self.0.content(), other.0.content())
+    }
+}
+
+/// Creates a style when `deps` change and claims its registration while the component is mounted.
+///
+/// The claim on the previous style is released when the style changes or the component is
+/// unmounted. A style is unregistered once all components that use it have released it.
+#[hook]
+pub(crate) fn use_held_style<S, D, F>(deps: D, create: F) -> S
+where
+    S: HoldStyle + Clone + 'static,
+    D: PartialEq + 'static,
+    F: FnOnce(&D) -> S,
+{
+    let style = (*use_memo(create, deps)).clone();
+    use_claim_style(style.clone());
+
+    style
+}
+
+/// Claims the registration of a style while the component is mounted.
+///
+/// The claim is moved to a new style when the style changes.
+#[hook]
+pub(crate) fn use_claim_style<S>(style: S)
+where
+    S: HoldStyle + 'static,
+{
+    use_effect_with_deps(
+        |style| {
+            let hold = style.0.hold();
+            move || drop(hold)
+        },
+        HeldStyle(style),
+    );
+}
+
+/// Creates the style of a component generated by [`styled!`](crate::yew::styled).
+#[cfg(feature = "macros")]
+fn create_styled_style(css: &StyleSource, mgr: &StyleManager) -> Style {
+    let mgr = css.manager().unwrap_or(mgr);
+
+    Style::new_unpinned(css.clone(), mgr).expect_display("failed to create style")
+}
+
+/// Creates the style of a component generated by [`styled!`](crate::yew::styled).
+///
+/// The source carries the contextual manager, so the style is created again when the manager
+/// changes. The previous style is released like in `use_style`.
+///
+/// Returns the style and the `style` attribute of the element, which declares the custom
+/// properties of the source before the declarations of `style`.
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+#[hook]
+pub fn use_styled_source(css: StyleSource, style: Option<AttrValue>) -> (Style, Option<AttrValue>) {
+    let mgr = use_style_manager();
+    let (css, vars) = css.split_vars();
+
+    let style_attr = match style {
+        _ if vars.is_empty() => style,
+        Some(m) => Some(format!("{} {}", vars, m).into()),
+        None => Some(vars.into()),
+    };
+
+    let style = use_held_style((css, mgr), |(css, mgr)| create_styled_style(css, mgr));
+
+    (style, style_attr)
+}
+
+#[cfg(all(test, feature = "macros"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_styled_style_released() {
+        let mgr = StyleManager::builder()
+            .build()
+            .expect("Failed to create manager.");
+        let src = |color: &str| -> StyleSource {
+            format!("color: {};", color)
+                .parse::<StyleSource>()
+                .expect("Failed to parse style.")
+                .with_manager(mgr.clone())
+        };
+
+        let red = create_styled_style(&src("red"), &StyleManager::default());
+        let red_hold = red.hold();
+        assert_eq!(mgr.style_count(), 1);
+
+        // The style created for the previous properties is unregistered once it is released.
+        let blue = create_styled_style(&src("blue"), &StyleManager::default());
+        let blue_hold = blue.hold();
+        drop(red_hold);
+        assert_eq!(mgr.style_count(), 1);
+
+        drop(blue_hold);
+        assert_eq!(mgr.style_count(), 0);
+    }
+}
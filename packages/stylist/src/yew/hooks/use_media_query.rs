@@ -1,35 +1,224 @@
+use std::rc::Rc;
+
 use gloo_events::EventListener;
+use web_sys::MediaQueryList;
 use yew::prelude::*;
 
+#[cfg(target_arch = "wasm32")]
 use crate::arch::window;
 
+#[cfg(target_arch = "wasm32")]
+fn match_media(query: &str) -> Option<MediaQueryList> {
+    window().ok()?.match_media(query).ok().flatten()
+}
+
+// There is no window to query outside of the browser.
+#[cfg(not(target_arch = "wasm32"))]
+#[allow(unused_variables)]
+fn match_media(query: &str) -> Option<MediaQueryList> {
+    None
+}
+
+fn matches_or(query: &str, fallback: bool) -> bool {
+    match_media(query).map(|m| m.matches()).unwrap_or(fallback)
+}
+
+/// The results of a list of queries.
+#[derive(Debug, PartialEq)]
+struct MediaMatches {
+    queries: Rc<Vec<String>>,
+    matches: Vec<bool>,
+}
+
+/// Subscribes to changes of queries, returns the listeners that need to be kept alive.
+fn listen_all(
+    queries: &Rc<Vec<String>>,
+    state: UseStateHandle<MediaMatches>,
+    fallback: bool,
+) -> Vec<EventListener> {
+    let lists: Rc<Vec<_>> = Rc::new(queries.iter().map(|m| match_media(m)).collect());
+
+    let current = {
+        let queries = queries.clone();
+        let lists = lists.clone();
+
+        move || MediaMatches {
+            queries: queries.clone(),
+            matches: lists
+                .iter()
+                .map(|m| m.as_ref().map(|m| m.matches()).unwrap_or(fallback))
+                .collect(),
+        }
+    };
+
+    let matches = current();
+    if *state != matches {
+        state.set(matches);
+    }
+
+    lists
+        .iter()
+        .flatten()
+        .map(|list| {
+            let state = state.clone();
+            let current = current.clone();
+
+            EventListener::new(list, "change", move |_event| state.set(current()))
+        })
+        .collect()
+}
+
 /// A hook to provide media query.
 ///
 /// This hook will return the result of whether the provided query matches and updates when the
-/// result changes.
+/// result changes. When `query` changes, the new query is evaluated while rendering and the hook
+/// subscribes to it.
+///
+/// When media queries are not available (e.g.: during server-side rendering), `false` is
+/// returned. Use [`use_media_query_with_fallback`] to configure the value.
 #[cfg(feature = "yew_use_media_query")]
 #[cfg_attr(feature = "yew_use_media_query", hook)]
 pub fn use_media_query(query: &str) -> bool {
-    let match_media = || {
-        window()
-            .ok()
-            .and_then(|m| m.match_media(query).ok())
-            .flatten()
-            .expect("Failed to query media")
-    };
+    let queries = use_memo(|query| vec![query.clone()], query.to_string());
+    let matches = use_media_matches(queries, false, false);
 
-    let state = use_state(|| match_media().matches());
-    let state_clone = state.clone();
+    matches.first().copied().unwrap_or(false)
+}
 
-    // Cached until end of component cycle.
-    use_state(move || {
-        let match_media = match_media();
-        let match_media_clone = match_media.clone();
+/// A hook to provide media query with a fallback value.
+///
+/// This hook is equivalent to [`use_media_query`], but it returns `fallback` until the component
+/// is rendered in the browser. As the first render returns `fallback` on both the server and the
+/// client, the result is consistent during hydration. The actual result is applied after the
+/// component is rendered.
+#[cfg(feature = "yew_use_media_query")]
+#[cfg_attr(feature = "yew_use_media_query", hook)]
+pub fn use_media_query_with_fallback(query: &str, fallback: bool) -> bool {
+    let queries = use_memo(|query| vec![query.clone()], query.to_string());
+    let matches = use_media_matches(queries, true, fallback);
 
-        EventListener::new(&match_media, "change", move |_event| {
-            state_clone.set(match_media_clone.matches());
-        })
+    matches.first().copied().unwrap_or(fallback)
+}
+
+/// A hook to provide multiple media queries.
+///
+/// This hook returns whether each of the provided queries matches, in the order of `queries`, and
+/// updates when any of the results changes. When media queries are not available (e.g.: during
+/// server-side rendering), `false` is returned for all queries.
+///
+/// # Example
+///
+/// ```
+/// use stylist::yew::use_media_queries;
+/// use yew::prelude::*;
+///
+/// #[function_component(Comp)]
+/// fn comp() -> Html {
+///     let matches = use_media_queries(&["(min-width: 600px)", "(prefers-color-scheme: dark)"]);
+///     let is_wide = matches[0];
+///     let is_dark = matches[1];
+///
+///     Html::default()
+/// }
+/// ```
+#[cfg(feature = "yew_use_media_query")]
+#[cfg_attr(feature = "yew_use_media_query", hook)]
+pub fn use_media_queries(queries: &[&str]) -> Vec<bool> {
+    let queries = use_memo(
+        |queries| queries.clone(),
+        queries.iter().map(|m| m.to_string()).collect::<Vec<_>>(),
+    );
+
+    use_media_matches(queries, false, false)
+}
+
+/// A hook to select the current breakpoint from a table of named breakpoints.
+///
+/// Each breakpoint is a name and the minimum width of the viewport in pixels. The hook returns the
+/// name of the breakpoint with the largest minimum width that matches and updates when the width of
+/// the viewport crosses a breakpoint. `None` is returned if no breakpoint matches or media queries
+/// are not available (e.g.: during server-side rendering).
+///
+/// # Example
+///
+/// ```
+/// use stylist::yew::use_breakpoint;
+/// use yew::prelude::*;
+///
+/// const BREAKPOINTS: &[(&str, u32)] = &[("sm", 640), ("md", 768), ("lg", 1024)];
+///
+/// #[function_component(Comp)]
+/// fn comp() -> Html {
+///     let breakpoint = use_breakpoint(BREAKPOINTS);
+///
+///     match breakpoint {
+///         Some("lg") => html! {<div>{"Large"}</div>},
+///         _ => html! {<div>{"Small"}</div>},
+///     }
+/// }
+/// ```
+#[cfg(feature = "yew_use_media_query")]
+#[cfg_attr(feature = "yew_use_media_query", hook)]
+pub fn use_breakpoint(breakpoints: &[(&'static str, u32)]) -> Option<&'static str> {
+    let queries: Vec<_> = breakpoints
+        .iter()
+        .map(|(_, width)| format!("(min-width: {}px)", width))
+        .collect();
+    let queries: Vec<_> = queries.iter().map(|m| m.as_str()).collect();
+
+    let matches = use_media_queries(&queries);
+
+    breakpoints
+        .iter()
+        .zip(matches)
+        .filter(|(_, matches)| *matches)
+        .max_by_key(|((_, width), _)| *width)
+        .map(|((name, _), _)| *name)
+}
+
+/// Returns whether each of the queries matches and subscribes to changes of the results.
+///
+/// If `defer` is `true`, `fallback` is returned until the component is rendered. When the queries
+/// change, they are evaluated during the render, so the results always belong to the current
+/// queries.
+#[hook]
+fn use_media_matches(queries: Rc<Vec<String>>, defer: bool, fallback: bool) -> Vec<bool> {
+    let state = use_state(|| MediaMatches {
+        queries: queries.clone(),
+        matches: queries
+            .iter()
+            .map(|m| {
+                if defer {
+                    fallback
+                } else {
+                    matches_or(m, fallback)
+                }
+            })
+            .collect(),
     });
+    use_media_query_subscription(queries.clone(), state.clone(), fallback);
+
+    // The state is only updated for new queries after the component is rendered.
+    if Rc::ptr_eq(&state.queries, &queries) {
+        state.matches.clone()
+    } else {
+        queries.iter().map(|m| matches_or(m, fallback)).collect()
+    }
+}
+
+/// Subscribes to the queries and resubscribes when the queries change.
+#[hook]
+fn use_media_query_subscription(
+    queries: Rc<Vec<String>>,
+    state: UseStateHandle<MediaMatches>,
+    fallback: bool,
+) {
+    use_effect_with_deps(
+        move |queries| {
+            let listeners = listen_all(queries, state, fallback);
 
-    *state
+            move || drop(listeners)
+        },
+        queries,
+    );
 }
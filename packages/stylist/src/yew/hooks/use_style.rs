@@ -2,11 +2,29 @@ use yew::prelude::*;
 
 use stylist_core::ResultDisplay;
 
+use super::{use_held_style, use_style_manager};
 use crate::manager::StyleManager;
 use crate::{Style, StyleSource};
 
+fn create_style(css: &StyleSource, mgr: &StyleManager) -> Style {
+    Style::new_unpinned(css.clone(), mgr).expect_display("failed to create style")
+}
+
 /// A hook to create auto updating [`Style`]s.
 ///
+/// The style is only created again when the css or the [`StyleManager`] changes. Sources that
+/// share the same stylesheet (e.g.: a [`css!`](crate::css) without interpolations) are compared
+/// by pointer first, so rendering a component again does not look up the style in the registry.
+///
+/// When the style changes, the previous style is unregistered, so a component that animates a
+/// value does not leave a trail of registered styles behind. The style is also unregistered when
+/// the component is unmounted. A style used by multiple components is unregistered after the last
+/// of them releases it.
+///
+/// A source created with `#![css_vars]` declares its custom properties in the style, so each
+/// distinct value creates a new style. Use [`use_style_with_vars`] to apply them to the `style`
+/// attribute of the element instead.
+///
 /// # Example
 ///
 /// ```
@@ -27,8 +45,58 @@ where
     Css: TryInto<StyleSource>,
     crate::Error: From<Css::Error>,
 {
-    let mgr = use_context::<StyleManager>().unwrap_or_default();
+    let mgr = use_style_manager();
+    let css: StyleSource = css
+        .try_into()
+        .map_err(crate::Error::from)
+        .expect_display("failed to create style");
+
+    use_held_style((css, mgr), |(css, mgr)| create_style(css, mgr))
+}
+
+/// A hook to create auto updating [`Style`]s that applies the custom properties of the source to
+/// the element.
+///
+/// Returns the style created from the source without its custom properties and the declarations
+/// of the custom properties for the `style` attribute of the element, see
+/// [`StyleSource::split_vars`]. The style stays the same no matter the values, so a value that
+/// changes often does not create new styles. The style is released like in [`use_style`].
+///
+/// # Example
+///
+/// ```
+/// use stylist::css;
+/// use stylist::yew::use_style_with_vars;
+/// use yew::prelude::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct Props {
+///     width: u32,
+/// }
+///
+/// #[function_component(Comp)]
+/// fn comp(props: &Props) -> Html {
+///     let width = format!("{}px", props.width);
+///     let (style, vars) = use_style_with_vars(css!(#![css_vars] width: ${width};));
+///
+///     html! {<div class={style} style={vars}>{"Hello world!"}</div>}
+/// }
+/// ```
+#[cfg(feature = "yew_use_style")]
+#[cfg_attr(feature = "yew_use_style", hook)]
+pub fn use_style_with_vars<Css>(css: Css) -> (Style, String)
+where
+    Css: TryInto<StyleSource>,
+    crate::Error: From<Css::Error>,
+{
+    let mgr = use_style_manager();
+    let css: StyleSource = css
+        .try_into()
+        .map_err(crate::Error::from)
+        .expect_display("failed to create style");
+    let (css, vars) = css.split_vars();
+
+    let style = use_held_style((css, mgr), |(css, mgr)| create_style(css, mgr));
 
-    // It does not make sense to unmount a scoped style.
-    Style::new_with_manager(css, mgr).expect_display("failed to create style")
+    (style, vars)
 }
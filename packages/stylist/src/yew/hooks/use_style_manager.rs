@@ -0,0 +1,45 @@
+use yew::prelude::*;
+
+use crate::manager::{MountMode, StyleManager};
+
+/// A hook to get the [`StyleManager`] of the current component.
+///
+/// Returns the manager provided by a [`ManagerProvider`](crate::yew::ManagerProvider) or the
+/// default manager. If the manager uses [`MountMode::Render`], the mounts and unmounts queued
+/// while rendering are flushed after the component has rendered, before the browser paints.
+///
+/// The contextual [`css!`](crate::css) macro of a [`styled_component`](crate::yew::styled_component)
+/// and the other hooks of this module use this manager.
+///
+/// # Example
+///
+/// ```
+/// use stylist::yew::use_style_manager;
+/// use stylist::Style;
+/// use yew::prelude::*;
+///
+/// #[function_component(Comp)]
+/// fn comp() -> Html {
+///     let mgr = use_style_manager();
+///     let style = Style::new_with_manager("color: red;", mgr).unwrap();
+///
+///     html! {<div class={style}>{"Hello world!"}</div>}
+/// }
+/// ```
+#[hook]
+pub fn use_style_manager() -> StyleManager {
+    let mgr = use_context::<StyleManager>().unwrap_or_default();
+
+    {
+        let mgr = mgr.clone();
+        use_effect(move || {
+            if mgr.mount_mode() == MountMode::Render {
+                let _result = mgr.flush();
+            }
+
+            || {}
+        });
+    }
+
+    mgr
+}
@@ -58,6 +58,63 @@ pub use stylist_macros::styled_component;
 #[cfg(feature = "macros")]
 pub use stylist_macros::styled_component_impl;
 
+/// A procedural macro to create a function component that renders an HTML element with a style.
+///
+/// The component renders the element with a class of the style, its `children` and the `class`,
+/// `id` and `style` attributes passed to the component. The `onclick`, `onmouseenter`,
+/// `onmouseleave`, `onfocus` and `onblur` listeners are also forwarded. The properties of the
+/// component are generated as `{Name}Props`.
+///
+/// Components of void elements (e.g.: `input`, `img` or `br`) cannot have children and have no
+/// `children` property.
+///
+/// No other attributes or listeners are forwarded, as yew cannot spread arbitrary attributes onto
+/// an element. Use [`styled_component`] to render an element that needs further attributes (e.g.:
+/// `title`, `aria-*` or `onkeydown`).
+///
+/// The style is created like with the `use_style` hook, so the style created for a previous value
+/// of the properties is unregistered once it is no longer used. If the style is created with
+/// `#![css_vars]`, the custom properties are applied to the `style` attribute of the element, so
+/// the component keeps one class no matter the values.
+///
+/// Additional properties can be declared in braces after the name of the element and are
+/// available to the style as `props`. Like in [`styled_component`], the [`css!`](crate::css)
+/// macro of the style is aware of the contextual style manager.
+///
+/// # Example:
+///
+/// ```
+/// use stylist::yew::styled;
+/// use yew::prelude::*;
+///
+/// styled!(pub Title, "h1", css!("font-size: 2em;"));
+///
+/// styled! {
+///     /// A button with a configurable color.
+///     pub Button("button") {
+///         #[prop_or_default]
+///         pub primary: bool,
+///     } => css!(
+///         color: ${if props.primary { "white" } else { "black" }};
+///         border: none;
+///     )
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let onclick = Callback::from(|_| ());
+///
+///     html! {
+///         <>
+///             <Title>{"Hello!"}</Title>
+///             <Button primary=true class="large" {onclick}>{"Click Me!"}</Button>
+///         </>
+///     }
+/// }
+/// ```
+#[cfg(feature = "macros")]
+pub use stylist_macros::styled;
+
 /// A procedural macro hook that parses a string literal or an inline stylesheet to create auto
 /// updating [`Style`]s.
 ///
@@ -91,9 +148,18 @@ impl ImplicitClone for StyleManager {}
 mod global;
 mod hooks;
 mod provider;
+mod ssr;
+mod styled_context;
+mod theme;
 
 pub use global::{Global, GlobalProps};
 pub use provider::{ManagerProvider, ManagerProviderProps};
+pub use ssr::{
+    HydrationStyleProvider, HydrationStyleProviderProps, ServerStyleProvider,
+    ServerStyleProviderProps, ServerStyles,
+};
+pub use styled_context::{StyleCache, StyledContext};
+pub use theme::{use_theme, ThemeProvider, ThemeProviderProps};
 
 pub use hooks::*;
 
@@ -108,12 +174,8 @@ impl From<Style> for Classes {
 impl From<StyleSource> for Classes {
     fn from(style_src: StyleSource) -> Self {
         let mut classes = Self::new();
-        #[cfg(all(debug_assertions, feature = "debug_style_locations"))]
-        let location = style_src.location.clone();
         let style = style_src.into_style();
         classes.push(style.get_class_name().to_string());
-        #[cfg(all(debug_assertions, feature = "debug_style_locations"))]
-        classes.push(location);
         classes
     }
 }
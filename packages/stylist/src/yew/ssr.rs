@@ -0,0 +1,206 @@
+use std::sync::{Arc, Mutex};
+
+use stylist_core::ResultDisplay;
+use yew::prelude::*;
+
+use super::ManagerProvider;
+use crate::manager::{CounterClassNameGenerator, StyleManager, StyleManagerBuilder};
+
+/// The styles collected from a server-side rendered application.
+///
+/// This is passed to [`ServerStyleProvider`] and read with [`ServerStyles::render_head`] after the
+/// application is rendered. It can be sent across threads, so it can be passed as a property of a
+/// component rendered with [`ServerRenderer`](yew::ServerRenderer).
+#[derive(Debug, Clone, Default)]
+pub struct ServerStyles {
+    inner: Arc<Mutex<Option<String>>>,
+}
+
+impl PartialEq for ServerStyles {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl ServerStyles {
+    /// Creates a new collection of styles for a render.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the `<style />` tags of all styles created during the render, to be inserted into
+    /// the `<head />` element of the page.
+    ///
+    /// An empty string is returned if the [`ServerStyleProvider`] has not finished rendering.
+    pub fn render_head(&self) -> String {
+        self.inner
+            .lock()
+            .expect("failed to lock server styles")
+            .clone()
+            .unwrap_or_default()
+    }
+}
+
+/// Writes the styles of a manager into [`ServerStyles`] when the provider is destroyed.
+#[derive(Debug)]
+struct StyleWriter {
+    manager: StyleManager,
+    styles: ServerStyles,
+}
+
+impl Drop for StyleWriter {
+    fn drop(&mut self) {
+        if let Ok(mut m) = self.styles.inner.lock() {
+            *m = Some(self.manager.render_style_tags());
+        }
+    }
+}
+
+// The client has to create class names in the same way as the server for them to be consistent.
+fn create_manager(
+    builder: Option<&Callback<(), StyleManagerBuilder>>,
+    hydrate: bool,
+) -> StyleManager {
+    builder
+        .map(|m| m.emit(()))
+        .unwrap_or_default()
+        .class_name_generator(CounterClassNameGenerator::new())
+        .hydrate(hydrate)
+        .build()
+        .expect_display("Failed to create style manager.")
+}
+
+/// The properties for [`ServerStyleProvider`] Component, please see its documentation for usage.
+#[derive(Properties, Clone, Debug, PartialEq)]
+pub struct ServerStyleProviderProps {
+    pub styles: ServerStyles,
+    /// Creates the builder of the manager of each render, e.g.: to set a prefix or the `nonce` of
+    /// `<style />` tags.
+    ///
+    /// The class name generator is always replaced with a [`CounterClassNameGenerator`]. Use the
+    /// same configuration for the [`HydrationStyleProvider`].
+    #[prop_or_default]
+    pub builder: Option<Callback<(), StyleManagerBuilder>>,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// A Context Provider to collect styles created during server-side rendering.
+///
+/// Each render creates a new [`StyleManager`], so styles of different requests do not mix. The
+/// manager is built from the `builder` property if it is set, so the `<style />` tags carry the
+/// configured prefix, `nonce` and attributes. The styles are written into [`ServerStyles`] once
+/// the render is finished. Use [`HydrationStyleProvider`] on the client to reuse the rendered
+/// styles.
+///
+/// # Example:
+///
+/// ```
+/// use stylist::yew::{styled_component, ServerStyleProvider, ServerStyles};
+/// use yew::prelude::*;
+///
+/// #[styled_component(Content)]
+/// fn content() -> Html {
+///     html! {<div class={css!("color: red;")}>{"Hello!"}</div>}
+/// }
+///
+/// #[derive(Properties, PartialEq)]
+/// struct AppProps {
+///     styles: ServerStyles,
+/// }
+///
+/// #[function_component(App)]
+/// fn app(props: &AppProps) -> Html {
+///     html! {
+///         <ServerStyleProvider styles={props.styles.clone()}>
+///             <Content />
+///         </ServerStyleProvider>
+///     }
+/// }
+///
+/// # async fn render() -> String {
+/// let styles = ServerStyles::new();
+/// let body = yew::ServerRenderer::<App>::with_props({
+///     let styles = styles.clone();
+///     move || AppProps { styles }
+/// })
+/// .render()
+/// .await;
+///
+/// format!(
+///     "<html><head>{}</head><body>{}</body></html>",
+///     styles.render_head(),
+///     body
+/// )
+/// # }
+/// ```
+#[function_component(ServerStyleProvider)]
+pub fn server_style_provider(props: &ServerStyleProviderProps) -> Html {
+    let writer = use_state(|| StyleWriter {
+        manager: create_manager(props.builder.as_ref(), false),
+        styles: props.styles.clone(),
+    });
+
+    html! {
+        <ManagerProvider manager={writer.manager.clone()}>
+            {props.children.clone()}
+        </ManagerProvider>
+    }
+}
+
+/// The properties for [`HydrationStyleProvider`] Component, please see its documentation for
+/// usage.
+#[derive(Properties, Clone, Debug, PartialEq)]
+pub struct HydrationStyleProviderProps {
+    /// Creates the builder of the manager, see [`ServerStyleProviderProps::builder`].
+    #[prop_or_default]
+    pub builder: Option<Callback<(), StyleManagerBuilder>>,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// A Context Provider to reuse styles rendered by [`ServerStyleProvider`] on the client.
+///
+/// The class names of styles are created in the same way as on the server and the `<style />`
+/// tags rendered by [`ServerStyles::render_head`] are adopted instead of being mounted again.
+/// Styles have to be created in the same order as on the server to receive the same class names.
+/// The tags of styles that have not been created when the provider is first rendered are removed.
+///
+/// The manager has to be configured like the manager of the server, so the `builder` property
+/// should create the same builder as on the server.
+///
+/// # Example:
+///
+/// ```
+/// use stylist::yew::HydrationStyleProvider;
+/// use yew::prelude::*;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let children = Html::default();
+///     html! {
+///         <HydrationStyleProvider>
+///             {children}
+///         </HydrationStyleProvider>
+///     }
+/// }
+/// ```
+#[function_component(HydrationStyleProvider)]
+pub fn hydration_style_provider(props: &HydrationStyleProviderProps) -> Html {
+    let manager = use_state(|| create_manager(props.builder.as_ref(), true));
+
+    // Children are rendered before the effects of the provider run.
+    use_effect_with_deps(
+        |manager| {
+            let _result = manager.remove_rendered();
+            || ()
+        },
+        (*manager).clone(),
+    );
+
+    html! {
+        <ManagerProvider manager={(*manager).clone()}>
+            {props.children.clone()}
+        </ManagerProvider>
+    }
+}
@@ -0,0 +1,218 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::panic::Location;
+
+use yew::html::{BaseComponent, Context};
+use yew::Callback;
+
+use stylist_core::ResultDisplay;
+
+use crate::hold::HoldStyle;
+#[cfg(target_arch = "wasm32")]
+use crate::hold::StyleHold;
+use crate::manager::StyleManager;
+use crate::{Style, StyleSource};
+
+/// Returns the name of a component type without its path and generic parameters.
+fn component_name<COMP>() -> &'static str {
+    let name = std::any::type_name::<COMP>();
+    let name = name.split('<').next().unwrap_or(name);
+
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// The source a cached style has been created from.
+#[derive(Debug, PartialEq)]
+enum CachedSource {
+    Source(StyleSource),
+    #[cfg(feature = "parser")]
+    Str(String),
+}
+
+#[derive(Debug)]
+struct CachedStyle {
+    src: CachedSource,
+    style: Style,
+    // The style is claimed while the component is alive, see `use_style`.
+    //
+    // Effects do not run during server-side rendering, so `use_style` never claims styles there.
+    // Styles are only claimed in the browser as well, so styles created on the server stay
+    // registered until they are rendered with `StyleManager::render_style_tags`.
+    #[cfg(target_arch = "wasm32")]
+    _hold: StyleHold,
+}
+
+/// The styles of a struct component instance, created with [`StyledContext`].
+///
+/// Each place [`StyledContext::style`] or [`StyledContext::style_str`] is called from keeps the
+/// style it has created last. The style is only created again when the css or the
+/// [`StyleManager`] changes.
+///
+/// In the browser, the previous style is unregistered when it is replaced and all styles are
+/// unregistered when the cache is dropped, unless they are used elsewhere. Store the cache in the
+/// component, so it is dropped when the component is destroyed.
+#[derive(Debug, Default)]
+pub struct StyleCache {
+    styles: RefCell<HashMap<&'static Location<'static>, CachedStyle>>,
+}
+
+impl StyleCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the style cached for a location or creates it if the source or the manager has
+    /// changed.
+    fn get_or_create<F>(
+        &self,
+        location: &'static Location<'static>,
+        src: CachedSource,
+        mgr: &StyleManager,
+        create: F,
+    ) -> Style
+    where
+        F: FnOnce() -> Style,
+    {
+        if let Some(m) = self.styles.borrow().get(location) {
+            if m.src == src && m.style.content().manager() == mgr {
+                return m.style.clone();
+            }
+        }
+
+        let style = create();
+        let cached = CachedStyle {
+            src,
+            style: style.clone(),
+            #[cfg(target_arch = "wasm32")]
+            _hold: style.hold(),
+        };
+        // The replaced style is dropped after the cache is released as dropping may unmount.
+        let replaced = self.styles.borrow_mut().insert(location, cached);
+        drop(replaced);
+
+        style
+    }
+}
+
+/// An extension trait to create styles in struct components.
+///
+/// This is the struct component counterpart of [`styled_component`](super::styled_component).
+/// Styles are created with the [`StyleManager`] provided by the nearest
+/// [`ManagerProvider`](super::ManagerProvider) and carry the name of the component, which
+/// replaces the name attached by [`css!`](crate::css).
+///
+/// Styles are cached in a [`StyleCache`] owned by the component, so calling
+/// [`view`](yew::Component::view) again neither parses nor registers a style again unless its css
+/// or the manager changes.
+///
+/// # Example:
+///
+/// ```
+/// use stylist::css;
+/// use stylist::yew::{StyleCache, StyledContext};
+/// use yew::prelude::*;
+///
+/// struct Comp {
+///     styles: StyleCache,
+/// }
+///
+/// impl Component for Comp {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_ctx: &Context<Self>) -> Self {
+///         Self {
+///             styles: StyleCache::new(),
+///         }
+///     }
+///
+///     fn view(&self, ctx: &Context<Self>) -> Html {
+///         let title = ctx.style_str(&self.styles, "font-size: 2em;");
+///         let content = ctx.style(&self.styles, css!(color: red;));
+///
+///         html! {
+///             <div>
+///                 <h1 class={title}>{"Hello!"}</h1>
+///                 <div class={content}>{"World!"}</div>
+///             </div>
+///         }
+///     }
+/// }
+/// ```
+pub trait StyledContext {
+    /// Returns the contextual [`StyleManager`] or the default manager if no manager is provided.
+    ///
+    /// The component is not subscribed to the manager and is not re-rendered if the provided
+    /// manager changes.
+    fn style_manager(&self) -> StyleManager;
+
+    /// Creates a [`Style`] with the contextual [`StyleManager`] and caches it for the calling
+    /// location in `cache`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the style cannot be created.
+    #[track_caller]
+    fn style<Css>(&self, cache: &StyleCache, css: Css) -> Style
+    where
+        Css: Into<StyleSource>;
+
+    /// Parses a string and creates a [`Style`] with the contextual [`StyleManager`].
+    ///
+    /// The style is cached for the calling location in `cache`, so the string is only parsed
+    /// again when it changes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string cannot be parsed or the style cannot be created.
+    #[cfg(feature = "parser")]
+    #[track_caller]
+    fn style_str(&self, cache: &StyleCache, css: &str) -> Style;
+}
+
+impl<COMP> StyledContext for Context<COMP>
+where
+    COMP: BaseComponent,
+{
+    fn style_manager(&self) -> StyleManager {
+        self.link()
+            .context::<StyleManager>(Callback::noop())
+            .map(|(m, _)| m)
+            .unwrap_or_default()
+    }
+
+    #[track_caller]
+    fn style<Css>(&self, cache: &StyleCache, css: Css) -> Style
+    where
+        Css: Into<StyleSource>,
+    {
+        let css = css.into().with_name(component_name::<COMP>());
+        let mgr = self.style_manager();
+
+        cache.get_or_create(
+            Location::caller(),
+            CachedSource::Source(css.clone()),
+            &mgr,
+            || Style::new_unpinned(css, &mgr).expect_display("failed to create style"),
+        )
+    }
+
+    #[cfg(feature = "parser")]
+    #[track_caller]
+    fn style_str(&self, cache: &StyleCache, css: &str) -> Style {
+        let mgr = self.style_manager();
+
+        cache.get_or_create(
+            Location::caller(),
+            CachedSource::Str(css.to_string()),
+            &mgr,
+            || {
+                let src: StyleSource = css.try_into().expect_display("failed to parse style");
+                let src = src.with_name(component_name::<COMP>());
+
+                Style::new_unpinned(src, &mgr).expect_display("failed to create style")
+            },
+        )
+    }
+}
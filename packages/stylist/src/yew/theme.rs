@@ -0,0 +1,308 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use stylist_core::ResultDisplay;
+use yew::prelude::*;
+
+use super::{use_claim_style, use_style_manager};
+use crate::ast::Sheet;
+use crate::hold::HoldStyle;
+use crate::manager::StyleManager;
+use crate::style::StyleContent;
+use crate::{CssVars, GlobalStyle, Style};
+
+/// The properties for [`ThemeProvider`] Component, please see its documentation for usage.
+#[derive(Properties, Clone, Debug, PartialEq)]
+pub struct ThemeProviderProps<T>
+where
+    T: CssVars + Clone + PartialEq + 'static,
+{
+    pub theme: T,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// The theme provided by the nearest [`ThemeProvider`].
+struct ThemeContext<T> {
+    theme: Rc<T>,
+}
+
+impl<T> Clone for ThemeContext<T> {
+    fn clone(&self) -> Self {
+        Self {
+            theme: self.theme.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for ThemeContext<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.theme == other.theme
+    }
+}
+
+impl<T> fmt::Debug for ThemeContext<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThemeContext").finish_non_exhaustive()
+    }
+}
+
+/// The style that applies the custom properties of a theme.
+#[derive(Debug, Clone)]
+enum ThemeStyle {
+    Global(GlobalStyle),
+    Scoped(Style),
+}
+
+impl ThemeStyle {
+    fn new(sheet: Sheet, mgr: &StyleManager, nested: bool) -> Self {
+        if nested {
+            Self::Scoped(
+                Style::new_unpinned(sheet.into(), mgr).expect_display("Failed to create style."),
+            )
+        } else {
+            Self::Global(
+                GlobalStyle::new_unpinned(sheet.into(), mgr)
+                    .expect_display("Failed to create style."),
+            )
+        }
+    }
+
+    /// Replaces the custom properties in place, returns `false` if the style has to be created
+    /// again instead.
+    fn replace(&mut self, sheet: Sheet) -> bool {
+        // The style is shared with another provider of an equal theme.
+        if self.content().holders() > 1 {
+            return false;
+        }
+
+        match self {
+            Self::Global(ref mut m) => m.replace(sheet).is_ok(),
+            Self::Scoped(ref mut m) => m.replace(sheet).is_ok(),
+        }
+    }
+}
+
+impl HoldStyle for ThemeStyle {
+    fn content(&self) -> &Rc<StyleContent> {
+        match self {
+            Self::Global(ref m) => m.content(),
+            Self::Scoped(ref m) => m.content(),
+        }
+    }
+}
+
+/// The style of a provider and the theme it declares.
+struct ThemeState<T> {
+    theme: T,
+    style: ThemeStyle,
+}
+
+/// A Context Provider to provide a theme to child components.
+///
+/// The theme is applied to the document as custom properties (see [`CssVars`]). Styles that refer
+/// to values of the theme with `var(--name)` do not change when the theme changes, so switching
+/// themes only replaces the rule that declares the custom properties in place instead of
+/// recreating the style of every component.
+///
+/// The outermost provider declares the custom properties on `:root` with a [`GlobalStyle`]. A
+/// provider nested in another provider of the same theme type declares them with a scoped style on
+/// an element that wraps its children.
+///
+/// # Note
+///
+/// A nested provider renders its children inside a `<div style="display: contents;" />`. The
+/// element does not generate a box, but it is a part of the DOM tree, so it affects selectors such
+/// as `>` or `:first-child` and the element a parent renders its children into. Place the nested
+/// provider inside the element that should contain the children instead of between a parent and
+/// its children if this matters.
+///
+/// Theme changes are applied after the provider has rendered, like the styles of
+/// [`use_style`](super::use_style).
+///
+/// Child components can read the theme with [`use_theme`].
+///
+/// # Example:
+///
+/// ```
+/// use stylist::yew::{styled_component, use_theme, ThemeProvider};
+/// use stylist::CssVars;
+/// use yew::prelude::*;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Theme {
+///     primary: String,
+/// }
+///
+/// impl CssVars for Theme {
+///     fn css_vars(&self) -> Vec<(String, String)> {
+///         vec![("--theme-primary".to_string(), self.primary.clone())]
+///     }
+/// }
+///
+/// #[styled_component(Button)]
+/// fn button() -> Html {
+///     // The style of the button does not change with the theme.
+///     html! {<button class={css!("color: var(--theme-primary);")}>{"Click Me!"}</button>}
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let theme = Theme {
+///         primary: "red".to_string(),
+///     };
+///
+///     html! {
+///         <ThemeProvider<Theme> {theme}>
+///             <Button />
+///         </ThemeProvider<Theme>>
+///     }
+/// }
+/// ```
+#[function_component(ThemeProvider)]
+pub fn theme_provider<T>(props: &ThemeProviderProps<T>) -> Html
+where
+    T: CssVars + Clone + PartialEq + 'static,
+{
+    let mgr = use_style_manager();
+    let nested = use_context::<ThemeContext<T>>().is_some();
+
+    // The style is created again when the manager changes or the provider is nested in another
+    // provider. Changes of the theme are applied by the effect below.
+    let state = {
+        let theme = props.theme.clone();
+        use_memo(
+            move |(mgr, nested)| {
+                RefCell::new(ThemeState {
+                    style: ThemeStyle::new(theme.to_sheet(), mgr, *nested),
+                    theme,
+                })
+            },
+            (mgr.clone(), nested),
+        )
+    };
+
+    let force_update = use_force_update();
+    {
+        let state = state.clone();
+        use_effect_with_deps(
+            move |(theme, mgr, nested)| {
+                let mut state = state.borrow_mut();
+                if state.theme != *theme {
+                    let sheet = theme.to_sheet();
+                    if !state.style.replace(sheet.clone()) {
+                        state.style = ThemeStyle::new(sheet, mgr, *nested);
+                    }
+                    state.theme = theme.clone();
+
+                    // Renders again to claim the new style and to apply its class.
+                    force_update.force_update();
+                }
+
+                || ()
+            },
+            (props.theme.clone(), mgr, nested),
+        );
+    }
+
+    let style = state.borrow().style.clone();
+    use_claim_style(style.clone());
+
+    let ctx = ThemeContext {
+        theme: Rc::new(props.theme.clone()),
+    };
+
+    let children = html! {
+        <ContextProvider<ThemeContext<T>> context={ctx}>
+            {props.children.clone()}
+        </ContextProvider<ThemeContext<T>>>
+    };
+
+    match style {
+        ThemeStyle::Scoped(ref m) => html! {
+            <div class={m.clone()} style="display: contents;">{children}</div>
+        },
+        ThemeStyle::Global(_) => children,
+    }
+}
+
+/// A hook to read the theme provided by the nearest [`ThemeProvider`] of the same theme type.
+///
+/// The component is re-rendered when the theme changes.
+///
+/// # Panics
+///
+/// Panics if the component is not a child of a [`ThemeProvider<T>`](ThemeProvider).
+///
+/// # Example
+///
+/// ```
+/// use stylist::yew::use_theme;
+/// use stylist::CssVars;
+/// use yew::prelude::*;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Theme {
+///     name: String,
+/// }
+///
+/// impl CssVars for Theme {
+///     fn css_vars(&self) -> Vec<(String, String)> {
+///         Vec::new()
+///     }
+/// }
+///
+/// #[function_component(ThemeName)]
+/// fn theme_name() -> Html {
+///     let theme = use_theme::<Theme>();
+///     html! {<span>{theme.name.clone()}</span>}
+/// }
+/// ```
+#[hook]
+pub fn use_theme<T>() -> Rc<T>
+where
+    T: CssVars + Clone + PartialEq + 'static,
+{
+    use_context::<ThemeContext<T>>()
+        .expect("use_theme is called outside of a ThemeProvider")
+        .theme
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sheet(primary: &str) -> Sheet {
+        format!("--theme-primary: {};", primary)
+            .parse()
+            .expect("Failed to parse style.")
+    }
+
+    #[test]
+    fn test_replace() {
+        let mgr = StyleManager::builder()
+            .prefix("test-theme".into())
+            .build()
+            .expect("Failed to create manager.");
+
+        let mut style = ThemeStyle::new(sheet("red"), &mgr, false);
+        let _hold = style.hold();
+        let id = style.content().id().clone();
+
+        // The style is replaced in place while only one provider claims it.
+        assert!(style.replace(sheet("blue")));
+        assert_eq!(style.content().id(), &id);
+        assert!(style.content().get_style_str().contains("blue"));
+        // The claim is moved to the new content after rendering.
+        let _hold = style.hold();
+
+        // A style shared by providers with equal themes is not replaced.
+        let shared = ThemeStyle::new(sheet("blue"), &mgr, false);
+        let _shared_hold = shared.hold();
+        assert!(!style.replace(sheet("green")));
+    }
+}
@@ -0,0 +1,71 @@
+use stylist::ast::ToStyleStr;
+use stylist::{css, CssVars, Style};
+
+mod tokens {
+    use stylist::CssVars;
+
+    #[derive(CssVars)]
+    pub struct Spacing {
+        pub small: String,
+        pub large: String,
+    }
+}
+
+#[derive(CssVars)]
+struct DesignTokens {
+    primary_color: String,
+    #[css_vars(rename = "radius")]
+    border_radius: u32,
+    #[css_vars(nested)]
+    spacing: tokens::Spacing,
+    #[css_vars(skip)]
+    #[allow(dead_code)]
+    name: String,
+}
+
+#[test]
+fn test_derive_css_vars() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let theme = DesignTokens {
+        primary_color: "red".to_string(),
+        border_radius: 4,
+        spacing: tokens::Spacing {
+            small: "4px".to_string(),
+            large: "16px".to_string(),
+        },
+        name: "light".to_string(),
+    };
+
+    assert_eq!(
+        theme.to_sheet().to_style_str(None),
+        r#":root {
+    --design-tokens-primary-color: red;
+    --design-tokens-radius: 4;
+    --design-tokens-spacing-small: 4px;
+    --design-tokens-spacing-large: 16px;
+}
+"#
+    );
+
+    let vars = DesignTokens::vars();
+    assert_eq!(vars.primary_color(), "var(--design-tokens-primary-color)");
+    assert_eq!(vars.border_radius(), "var(--design-tokens-radius)");
+    assert_eq!(vars.spacing().large(), "var(--design-tokens-spacing-large)");
+    assert_eq!(tokens::Spacing::vars().small(), "var(--spacing-small)");
+
+    let style = Style::new(css!(
+        color: ${vars.primary_color()};
+        padding: ${vars.spacing().small()};
+    ))
+    .unwrap();
+    let expected = format!(
+        r#".{cls} {{
+    color: var(--design-tokens-primary-color);
+    padding: var(--design-tokens-spacing-small);
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(style.get_style_str(), expected);
+}
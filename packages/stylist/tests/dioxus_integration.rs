@@ -0,0 +1,88 @@
+#![cfg(feature = "dioxus_integration")]
+
+use std::cell::{Cell, RefCell};
+
+use dioxus::prelude::*;
+use stylist::dioxus::{use_global_style, use_style, ManagerProvider};
+use stylist::manager::{CounterClassNameGenerator, StyleManager};
+
+thread_local! {
+    static COLOR: Cell<&'static str> = const { Cell::new("red") };
+    static CONTENT_SCOPE: Cell<Option<ScopeId>> = const { Cell::new(None) };
+    static APP_SCOPE: Cell<Option<ScopeId>> = const { Cell::new(None) };
+    static MANAGER: RefCell<Option<StyleManager>> = const { RefCell::new(None) };
+}
+
+#[component]
+fn Content() -> Element {
+    CONTENT_SCOPE.with(|m| m.set(current_scope_id().ok()));
+
+    use_global_style("body { margin: 0; }");
+    let style = use_style(format!("color: {};", COLOR.with(|m| m.get())));
+    // Shares the style with `use_style` above.
+    use_style("color: red;");
+
+    rsx! { div { class: style, "Hello!" } }
+}
+
+#[component]
+fn App(manager: StyleManager) -> Element {
+    APP_SCOPE.with(|m| m.set(current_scope_id().ok()));
+    let manager = MANAGER.with(|m| m.borrow().clone()).unwrap_or(manager);
+
+    rsx! {
+        ManagerProvider { manager, Content {} }
+    }
+}
+
+#[test]
+fn test_dioxus_integration() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let manager = StyleManager::builder()
+        .prefix("dioxus".into())
+        .class_name_generator(CounterClassNameGenerator::new())
+        .build()
+        .unwrap();
+
+    let mut dom = VirtualDom::new_with_props(
+        App,
+        AppProps {
+            manager: manager.clone(),
+        },
+    );
+    dom.rebuild_in_place();
+
+    assert_eq!(
+        manager.render_style_tags(),
+        "<style data-style=\"dioxus-global-0\" data-style-rendered=\"\">body {\n    margin: 0;\n}\n</style>\
+         <style data-style=\"dioxus-1\" data-style-rendered=\"\">.dioxus-1 {\n    color: red;\n}\n</style>"
+    );
+
+    // The previous style is released when the css changes, it is kept while another hook holds it.
+    COLOR.with(|m| m.set("blue"));
+    dom.mark_dirty(CONTENT_SCOPE.with(|m| m.get()).unwrap());
+    dom.render_immediate_to_vec();
+
+    let styles = manager.render_style_tags();
+    assert_eq!(manager.style_count(), 3);
+    assert!(styles.contains("color: blue;"));
+    assert!(styles.contains("color: red;"));
+
+    // Styles are created with the new manager when the provided manager changes.
+    let next_manager = StyleManager::builder()
+        .prefix("next".into())
+        .build()
+        .unwrap();
+    MANAGER.with(|m| *m.borrow_mut() = Some(next_manager.clone()));
+    dom.mark_dirty(APP_SCOPE.with(|m| m.get()).unwrap());
+    dom.render_immediate_to_vec();
+    // Renders the components that are notified of the new manager.
+    dom.render_immediate_to_vec();
+
+    assert_eq!(manager.style_count(), 0);
+    assert_eq!(next_manager.style_count(), 3);
+
+    drop(dom);
+    assert_eq!(next_manager.style_count(), 0);
+}
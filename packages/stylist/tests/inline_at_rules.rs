@@ -0,0 +1,159 @@
+use stylist::{css, Style};
+
+#[test]
+fn test_inline_at_keyframes() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let style = Style::new(css! {
+        animation: fade 1s;
+
+        @keyframes fade {
+            from {
+                opacity: 0;
+            }
+            25%, 50% {
+                opacity: 0.5;
+            }
+            to {
+                opacity: 1;
+            }
+        }
+    })
+    .unwrap();
+
+    let expected_result = format!(
+        r#".{cls} {{
+    animation: fade 1s;
+}}
+@keyframes fade {{
+    from {{
+        opacity: 0;
+    }}
+    25%, 50% {{
+        opacity: 0.5;
+    }}
+    to {{
+        opacity: 1;
+    }}
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(expected_result, style.get_style_str());
+}
+
+#[test]
+fn test_inline_at_font_face() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let style = Style::new(css! {
+        @font-face {
+            font-family: "Open Sans";
+            src: url("/fonts/OpenSans.woff2") format("woff2");
+            font-display: swap;
+        }
+
+        font-family: "Open Sans";
+    })
+    .unwrap();
+
+    let expected_result = format!(
+        r#"@font-face {{
+    font-family: "Open Sans";
+    src: url("/fonts/OpenSans.woff2") format("woff2");
+    font-display: swap;
+}}
+.{cls} {{
+    font-family: "Open Sans";
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(expected_result, style.get_style_str());
+}
+
+#[test]
+fn test_inline_at_layer() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let style = Style::new(css! {
+        @layer base, components;
+
+        @layer base {
+            color: red;
+
+            span {
+                color: blue;
+            }
+        }
+    })
+    .unwrap();
+
+    let expected_result = format!(
+        r#"@layer base,components;
+@layer base {{
+    .{cls} {{
+        color: red;
+    }}
+}}
+@layer base {{
+    .{cls} span {{
+        color: blue;
+    }}
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(expected_result, style.get_style_str());
+}
+
+#[test]
+fn test_inline_at_container() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let style = Style::new(css! {
+        container-type: inline-size;
+
+        @container sidebar (min-width: 400px) {
+            display: flex;
+        }
+    })
+    .unwrap();
+
+    let expected_result = format!(
+        r#".{cls} {{
+    container-type: inline-size;
+}}
+@container sidebar (min-width:400px) {{
+    .{cls} {{
+        display: flex;
+    }}
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(expected_result, style.get_style_str());
+}
+
+#[test]
+fn test_inline_at_container_functions() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let style = Style::new(css! {
+        @container scroll-state(stuck: top) and (min-width: 400px) {
+            box-shadow: none;
+        }
+    })
+    .unwrap();
+
+    let expected_result = format!(
+        r#"@container scroll-state(stuck:top) and (min-width:400px) {{
+    .{cls} {{
+        box-shadow: none;
+    }}
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(expected_result, style.get_style_str());
+}
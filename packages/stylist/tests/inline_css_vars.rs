@@ -0,0 +1,106 @@
+use stylist::{css, Style};
+
+#[test]
+fn test_inline_css_vars() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let create = |width: &str, color: &str| {
+        css! {
+            #![css_vars]
+            width: ${width};
+            border-color: ${color};
+            height: ${"10px"};
+        }
+    };
+
+    let src_a = create("100px", "red");
+    let src_b = create("200px", "blue");
+
+    assert_eq!(src_a.vars().len(), 2);
+    let width_var = src_a.vars()[0].0.clone();
+    let color_var = src_a.vars()[1].0.clone();
+    assert!(width_var.starts_with("--s-"));
+    assert_ne!(width_var, color_var);
+    assert_eq!(
+        src_a.vars_style(),
+        format!("{}: 100px; {}: red;", width_var, color_var)
+    );
+    assert_eq!(
+        src_b.vars_style(),
+        format!("{}: 200px; {}: blue;", width_var, color_var)
+    );
+
+    let style_a = Style::new(src_a.split_vars().0).unwrap();
+    let style_b = Style::new(src_b.split_vars().0).unwrap();
+    assert_eq!(style_a.get_class_name(), style_b.get_class_name());
+
+    let expected_result = format!(
+        r#".{cls} {{
+    width: var({width_var});
+    border-color: var({color_var});
+    height: 10px;
+}}
+"#,
+        cls = style_a.get_class_name(),
+        width_var = width_var,
+        color_var = color_var,
+    );
+    assert_eq!(expected_result, style_a.get_style_str());
+}
+
+#[test]
+fn test_inline_css_vars_shared() {
+    let src_a = {
+        let width = "100px";
+        css!(#![css_vars] width: ${width};)
+    };
+    let src_b = {
+        let width = "200px";
+        css!(#![css_vars] width: ${width};)
+    };
+
+    // The same macro text in different places uses the same custom properties and the same style.
+    assert_eq!(src_a.vars()[0].0, src_b.vars()[0].0);
+    assert_ne!(src_a.vars()[0].1, src_b.vars()[0].1);
+
+    let style_a = Style::new(src_a.split_vars().0).expect("Failed to create style");
+    let style_b = Style::new(src_b.split_vars().0).expect("Failed to create style");
+    assert_eq!(style_a.get_class_name(), style_b.get_class_name());
+}
+
+#[test]
+fn test_inline_css_vars_split() {
+    let width = "100px";
+    let src = css!(#![css_vars] width: ${width};);
+    let var_name = src.vars()[0].0.clone();
+
+    let (src, vars_style) = src.split_vars();
+    assert!(src.vars().is_empty());
+    assert_eq!(vars_style, format!("{}: 100px;", var_name));
+}
+
+#[cfg(feature = "yew_integration")]
+#[test]
+#[should_panic(expected = "StyleSource::split_vars")]
+fn test_inline_css_vars_as_class() {
+    let width = "100px";
+    let _classes = yew::Classes::from(css!(#![css_vars] width: ${width};));
+}
+
+#[test]
+fn test_inline_css_vars_declared() {
+    let width = "100px";
+    let src = css!(#![css_vars] width: ${width};);
+    let var_name = src.vars()[0].0.clone();
+
+    // A style created from a source that still carries custom properties declares them.
+    let style = Style::new(src).expect("Failed to create style");
+    assert_eq!(
+        style.get_style_str(),
+        format!(
+            ".{cls} {{\n    {var}: 100px;\n}}\n.{cls} {{\n    width: var({var});\n}}\n",
+            cls = style.get_class_name(),
+            var = var_name,
+        )
+    );
+}
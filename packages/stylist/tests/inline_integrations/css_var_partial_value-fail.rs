@@ -0,0 +1,7 @@
+fn main() {
+    let width = 100;
+    let _ = stylist::css! {
+        #![css_vars]
+        width: ${width}px;
+    };
+}
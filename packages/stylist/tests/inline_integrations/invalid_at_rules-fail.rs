@@ -0,0 +1,21 @@
+fn main() {
+    let _ = stylist::css! {
+        @keyframes fade {
+            opacity: 0;
+            middle {
+                opacity: 0.5;
+            }
+            50 {
+                opacity: 1;
+            }
+        }
+        @font-face stylist {
+            font-family: "Open Sans";
+        }
+        span {
+            @font-face {
+                font-family: "Open Sans";
+            }
+        }
+    };
+}
@@ -0,0 +1,9 @@
+fn main() {
+    let _ = stylist::css! {
+        colr: red;
+        display: flexx;
+        position: absolute;
+        overflow: hiden;
+        some-attribute: foo-value;
+    };
+}
@@ -0,0 +1,47 @@
+use stylist::{css, keyframes, Style};
+
+#[test]
+fn test_inline_keyframes() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let fade = keyframes! {
+        from {
+            opacity: 0;
+        }
+        50% {
+            opacity: 0.5;
+        }
+        to {
+            opacity: 1;
+        }
+    }
+    .unwrap();
+
+    let expected_result = format!(
+        r#"@keyframes {name} {{
+    from {{
+        opacity: 0;
+    }}
+    50% {{
+        opacity: 0.5;
+    }}
+    to {{
+        opacity: 1;
+    }}
+}}
+"#,
+        name = fade.name()
+    );
+    assert_eq!(expected_result, fade.get_style_str());
+
+    let style = Style::new(css!(animation-name: ${fade};)).unwrap();
+    let expected_result = format!(
+        r#".{cls} {{
+    animation-name: {name};
+}}
+"#,
+        cls = style.get_class_name(),
+        name = fade.name()
+    );
+    assert_eq!(expected_result, style.get_style_str());
+}
@@ -0,0 +1,110 @@
+use stylist::{css, Style};
+
+#[test]
+fn test_inline_nested_blocks() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let style = Style::new(css! {
+        .outer {
+            color: red;
+
+            &:hover {
+                color: blue;
+            }
+
+            .inner, span {
+                color: black;
+
+                &.active {
+                    color: white;
+                }
+            }
+
+            background-color: gray;
+        }
+    })
+    .unwrap();
+
+    let expected_result = format!(
+        r#".{cls} .outer {{
+    color: red;
+}}
+.{cls} .outer:hover {{
+    color: blue;
+}}
+.{cls} .outer .inner, .{cls} .outer span {{
+    color: black;
+}}
+.{cls} .outer .inner.active, .{cls} .outer span.active {{
+    color: white;
+}}
+.{cls} .outer {{
+    background-color: gray;
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(expected_result, style.get_style_str());
+}
+
+#[test]
+fn test_inline_nested_rules_in_blocks() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let style = Style::new(css! {
+        &.card {
+            padding: ${"4px"};
+
+            @media print {
+                padding: 0;
+
+                .title {
+                    display: none;
+                }
+            }
+        }
+    })
+    .unwrap();
+
+    let expected_result = format!(
+        r#".{cls}.card {{
+    padding: 4px;
+}}
+@media print {{
+    .{cls}.card {{
+        padding: 0;
+    }}
+}}
+@media print {{
+    .{cls}.card .title {{
+        display: none;
+    }}
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(expected_result, style.get_style_str());
+}
+
+#[test]
+fn test_inline_nested_blocks_multiple_parents() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let style = Style::new(css! {
+        .a, .b {
+            & + & {
+                margin-left: 4px;
+            }
+        }
+    })
+    .unwrap();
+
+    let expected_result = format!(
+        r#".{cls} .a+.a, .{cls} .a+.b, .{cls} .b+.a, .{cls} .b+.b {{
+    margin-left: 4px;
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(expected_result, style.get_style_str());
+}
@@ -0,0 +1,48 @@
+use stylist::{css, Style};
+
+#[test]
+fn test_inline_unchecked() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let style = Style::new(css! {
+        #![unchecked]
+        experimental-property: some-value;
+        display: not-a-display;
+    })
+    .unwrap();
+
+    let expected_result = format!(
+        r#".{cls} {{
+    experimental-property: some-value;
+    display: not-a-display;
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(expected_result, style.get_style_str());
+}
+
+#[test]
+fn test_inline_unchecked_prefixed() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let style = Style::new(css! {
+        --custom-color: red;
+        -webkit-line-clamp: 2;
+        display: -webkit-box;
+        color: var(--custom-color);
+    })
+    .unwrap();
+
+    let expected_result = format!(
+        r#".{cls} {{
+    --custom-color: red;
+    -webkit-line-clamp: 2;
+    display: -webkit-box;
+    color: var(--custom-color);
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(expected_result, style.get_style_str());
+}
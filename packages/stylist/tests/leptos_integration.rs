@@ -0,0 +1,83 @@
+#![cfg(feature = "leptos_integration")]
+
+use leptos::prelude::*;
+use stylist::css;
+use stylist::leptos::{styled_component, use_style, ClassName, Global, ManagerProvider};
+use stylist::manager::{CounterClassNameGenerator, StyleManager};
+
+#[styled_component]
+fn Content() -> impl IntoView {
+    let title = ClassName::from(use_style("font-size: 2em;"));
+    let content = ClassName::from(css!(color: red;));
+
+    view! {
+        <h1 class=title>"Hello!"</h1>
+        <div class=content>"World!"</div>
+    }
+}
+
+#[test]
+fn test_leptos_integration() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let manager = StyleManager::builder()
+        .prefix("leptos".into())
+        .class_name_generator(CounterClassNameGenerator::new())
+        .build()
+        .unwrap();
+
+    let owner = Owner::new();
+    let body = owner.with(|| {
+        let manager = manager.clone();
+
+        view! {
+            <ManagerProvider manager=manager>
+                <Global css=css!("body { margin: 0; }") />
+                <Content />
+            </ManagerProvider>
+        }
+        .to_html()
+    });
+
+    assert!(body.contains("<h1 class=\"leptos-1\">Hello!</h1>"));
+    assert!(body.contains("<div class=\"leptos-2\">World!</div>"));
+    assert_eq!(
+        manager.render_style_tags(),
+        "<style data-style=\"leptos-global-0\" data-style-rendered=\"\">body {\n    margin: 0;\n}\n</style>\
+         <style data-style=\"leptos-1\" data-style-rendered=\"\">.leptos-1 {\n    font-size: 2em;\n}\n</style>\
+         <style data-style=\"leptos-2\" data-style-rendered=\"\">.leptos-2 {\n    color: red;\n}\n</style>"
+    );
+
+    // Styles are released when the owner is cleaned up.
+    owner.cleanup();
+    assert_eq!(manager.style_count(), 0);
+}
+
+#[test]
+fn test_leptos_shared_global() {
+    let manager = StyleManager::builder()
+        .prefix("leptos-shared".into())
+        .build()
+        .unwrap();
+
+    let first = Owner::new();
+    let second = Owner::new();
+    for owner in [&first, &second] {
+        owner.with(|| {
+            let manager = manager.clone();
+
+            view! {
+                <ManagerProvider manager=manager>
+                    <Global css=css!("body { margin: 0; }") />
+                </ManagerProvider>
+            }
+            .to_html()
+        });
+    }
+
+    // The style is kept while another component uses it.
+    first.cleanup();
+    assert_eq!(manager.style_count(), 1);
+    second.cleanup();
+    assert_eq!(manager.style_count(), 0);
+}
@@ -0,0 +1,85 @@
+use stylist::{css, Style};
+
+struct Theme {
+    primary: String,
+    spacing: u32,
+}
+
+impl Theme {
+    fn radius(&self) -> &'static str {
+        "4px"
+    }
+}
+
+#[test]
+fn test_literal_implicit_capture() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let color = "red";
+    let theme = Theme {
+        primary: "blue".into(),
+        spacing: 8,
+    };
+
+    let style = Style::new(css!(
+        r#"
+            color: ${color};
+            background-color: ${theme.primary};
+            border-radius: ${theme.radius()};
+            padding: ${padding}px;
+        "#,
+        padding = theme.spacing,
+    ))
+    .unwrap();
+
+    let expected_result = format!(
+        r#".{cls} {{
+    color: red;
+    background-color: blue;
+    border-radius: 4px;
+    padding: 8px;
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(expected_result, style.get_style_str());
+}
+
+#[test]
+fn test_literal_positional_args() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let theme = Theme {
+        primary: "blue".into(),
+        spacing: 8,
+    };
+
+    let style = Style::new(css!(
+        r#"
+            color: ${0};
+            background-color: ${1.primary};
+            &:hover {
+                color: ${0};
+                margin: ${size}px;
+            }
+        "#,
+        "red",
+        theme,
+        size = 4,
+    ))
+    .unwrap();
+
+    let expected_result = format!(
+        r#".{cls} {{
+    color: red;
+    background-color: blue;
+}}
+.{cls}:hover {{
+    color: red;
+    margin: 4px;
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(expected_result, style.get_style_str());
+}
@@ -0,0 +1,82 @@
+use stylist::{css, variants};
+
+variants! {
+    pub struct ButtonStyle(ButtonProps) {
+        base: css!(border: none;),
+        variants: {
+            size: ButtonSize {
+                Small => css!(padding: 4px;),
+                Large => css!(padding: 16px;),
+            },
+            intent: ButtonIntent {
+                Primary => css!(color: blue;),
+                Danger => css!(color: red;),
+            },
+        },
+        compounds: [
+            { size: Large, intent: Danger } => css!(font-weight: bold;),
+        ],
+    }
+}
+
+variants! {
+    struct LinkStyle(LinkProps) {
+        base: css!(text-decoration: none;),
+        variants: {
+            tone: LinkTone {
+                Muted => css!(color: gray;),
+                Loud => css!(color: black;),
+            },
+        },
+    }
+}
+
+#[test]
+fn test_variants() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let button_style = ButtonStyle::new().expect("Failed to create variants.");
+    assert_eq!(button_style.variants().styles().len(), 4);
+    assert_eq!(ButtonProps::default().size, ButtonSize::Small);
+
+    let style = button_style.class(&ButtonProps::default());
+    let expected = format!(
+        r#".{cls} {{
+    border: none;
+    padding: 4px;
+    color: blue;
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(style.get_style_str(), expected);
+
+    let style = button_style.class(&ButtonProps {
+        size: ButtonSize::Large,
+        intent: ButtonIntent::Danger,
+    });
+    let expected = format!(
+        r#".{cls} {{
+    border: none;
+    padding: 16px;
+    color: red;
+    font-weight: bold;
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(style.get_style_str(), expected);
+
+    // Selecting a combination again returns the registered style.
+    let again = button_style.class(&ButtonProps {
+        size: ButtonSize::Large,
+        intent: ButtonIntent::Danger,
+    });
+    assert_eq!(again.get_class_name(), style.get_class_name());
+
+    let link_style = LinkStyle::new().expect("Failed to create variants.");
+    let style = link_style.class(&LinkProps {
+        tone: LinkTone::Loud,
+    });
+    assert!(style.get_style_str().contains("color: black;"));
+}
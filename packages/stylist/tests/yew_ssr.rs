@@ -0,0 +1,97 @@
+#![cfg(feature = "yew_integration")]
+
+use stylist::manager::{DebugClassNameGenerator, StyleManager};
+use stylist::yew::{styled_component, Global, ServerStyleProvider, ServerStyles};
+use yew::prelude::*;
+use yew::LocalServerRenderer;
+
+#[styled_component(Content)]
+fn content() -> Html {
+    html! {<div class={css!("color: red;")}>{"Hello!"}</div>}
+}
+
+#[derive(Properties, PartialEq)]
+struct AppProps {
+    styles: ServerStyles,
+}
+
+#[function_component(App)]
+fn app(props: &AppProps) -> Html {
+    html! {
+        <ServerStyleProvider styles={props.styles.clone()}>
+            <Global css="body { margin: 0; }" />
+            <Content />
+        </ServerStyleProvider>
+    }
+}
+
+async fn render() -> (String, String) {
+    let styles = ServerStyles::new();
+    let body = LocalServerRenderer::<App>::with_props(AppProps {
+        styles: styles.clone(),
+    })
+    .render()
+    .await;
+
+    (styles.render_head(), body)
+}
+
+#[tokio::test]
+async fn test_render_head() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let (head, body) = render().await;
+
+    assert_eq!(
+        head,
+        "<style data-style=\"stylist-global-0\" data-style-rendered=\"\">body {\n    margin: 0;\n}\n</style>\
+         <style data-style=\"stylist-1\" data-style-rendered=\"\">.stylist-1 {\n    color: red;\n}\n</style>"
+    );
+    assert!(body.contains("class=\"stylist-1\""));
+}
+
+#[tokio::test]
+async fn test_renders_are_isolated() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let (first_head, first_body) = render().await;
+    let (second_head, second_body) = render().await;
+
+    assert_eq!(first_head, second_head);
+    assert_eq!(first_body, second_body);
+}
+
+#[function_component(ConfiguredApp)]
+fn configured_app(props: &AppProps) -> Html {
+    let builder = Callback::from(|_| {
+        StyleManager::builder()
+            .prefix("ssr".into())
+            .nonce("abc".into())
+            .class_name_generator(DebugClassNameGenerator::new())
+    });
+
+    html! {
+        <ServerStyleProvider styles={props.styles.clone()} {builder}>
+            <Content />
+        </ServerStyleProvider>
+    }
+}
+
+#[tokio::test]
+async fn test_builder() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let styles = ServerStyles::new();
+    let body = LocalServerRenderer::<ConfiguredApp>::with_props(AppProps {
+        styles: styles.clone(),
+    })
+    .render()
+    .await;
+
+    // The prefix and the nonce are kept, class names are still created by a counter.
+    assert_eq!(
+        styles.render_head(),
+        "<style data-style=\"ssr-0\" nonce=\"abc\" data-style-rendered=\"\">.ssr-0 {\n    color: red;\n}\n</style>"
+    );
+    assert!(body.contains("class=\"ssr-0\""));
+}
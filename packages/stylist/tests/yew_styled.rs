@@ -0,0 +1,98 @@
+#![cfg(feature = "yew_integration")]
+
+use stylist::css;
+use stylist::manager::{CounterClassNameGenerator, StyleManager};
+use stylist::yew::{styled, use_style_with_vars, ManagerProvider};
+use yew::prelude::*;
+use yew::LocalServerRenderer;
+
+styled!(Title, "h1", css!("font-size: 2em;"));
+
+styled!(Input, "input", css!("border: none;"));
+
+styled! {
+    /// A button.
+    pub Button("button") {
+        #[prop_or_default]
+        pub primary: bool,
+    } => css!(
+        color: ${if props.primary { "white" } else { "black" }};
+    )
+}
+
+styled! {
+    pub Bar("div") {
+        pub width: u32,
+    } => css!(
+        #![css_vars]
+        width: ${format!("{}px", props.width)};
+    )
+}
+
+#[derive(Properties, PartialEq)]
+struct MeterProps {
+    width: u32,
+}
+
+#[function_component(Meter)]
+fn meter(props: &MeterProps) -> Html {
+    let width = format!("{}px", props.width);
+    let (style, vars) = use_style_with_vars(css!(#![css_vars] width: ${width};));
+
+    html! {<span class={style} style={vars} />}
+}
+
+#[function_component(App)]
+fn app() -> Html {
+    let mgr = use_state(|| {
+        StyleManager::builder()
+            .prefix("styled".into())
+            .class_name_generator(CounterClassNameGenerator::new())
+            .build()
+            .unwrap()
+    });
+
+    html! {
+        <ManagerProvider manager={(*mgr).clone()}>
+            <Title id="title">{"Hello!"}</Title>
+            <Input id="name" />
+            <Button primary=true class="large">{"Click Me!"}</Button>
+            <Button>{"Cancel"}</Button>
+            <Bar width=100 style="color: red;" />
+            <Meter width=100 />
+            <Meter width=200 />
+        </ManagerProvider>
+    }
+}
+
+#[tokio::test]
+async fn test_styled() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let body = LocalServerRenderer::<App>::new()
+        .hydratable(false)
+        .render()
+        .await;
+
+    let (body, bar) = body.split_at(body.find("<div").unwrap());
+    assert_eq!(
+        body,
+        "<h1 id=\"title\" class=\"styled-0\">Hello!</h1>\
+         <input id=\"name\" class=\"styled-1\">\
+         <button class=\"styled-2 large\">Click Me!</button>\
+         <button class=\"styled-3\">Cancel</button>"
+    );
+
+    // The custom properties are declared before the style passed to the component.
+    assert!(bar.contains("class=\"styled-4\""));
+    assert!(bar.contains("style=\"--s-"));
+    assert!(bar.contains(": 100px; color: red;\""));
+
+    // Both meters share a class and declare their widths inline.
+    let (bar, meters) = bar.split_at(bar.find("<span").unwrap());
+    assert!(bar.ends_with("</div>"));
+    assert_eq!(meters.matches("class=\"styled-5\"").count(), 2);
+    assert!(meters.contains(": 100px;\""));
+    assert!(meters.contains(": 200px;\""));
+    assert!(!meters.contains("styled-6"));
+}
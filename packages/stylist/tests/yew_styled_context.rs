@@ -0,0 +1,93 @@
+#![cfg(feature = "yew_integration")]
+
+use stylist::css;
+use stylist::manager::{CounterClassNameGenerator, StyleManager};
+use stylist::yew::{ManagerProvider, StyleCache, StyledContext};
+use yew::prelude::*;
+use yew::LocalServerRenderer;
+
+struct Comp {
+    styles: StyleCache,
+}
+
+impl Component for Comp {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            styles: StyleCache::new(),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let title = ctx.style_str(&self.styles, "font-size: 2em;");
+        let content = ctx.style(&self.styles, css!(color: red;));
+
+        assert_eq!(
+            ctx.style_str(&self.styles, "font-size: 2em;").id(),
+            title.id()
+        );
+
+        // Each location keeps the style it has created last.
+        let create = |css: &str| ctx.style_str(&self.styles, css);
+        let style = create("display: block;");
+        style.unregister();
+        assert_eq!(create("display: block;").id(), style.id());
+        assert!(ctx
+            .style_manager()
+            .get_by_class_name(style.get_class_name())
+            .is_none());
+
+        let flex = create("display: flex;");
+        assert_ne!(flex.id(), style.id());
+        flex.unregister();
+
+        html! {
+            <>
+                <h1 class={title}>{"Hello!"}</h1>
+                <div class={content}>{"World!"}</div>
+            </>
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct AppProps {
+    manager: StyleManager,
+}
+
+#[function_component(App)]
+fn app(props: &AppProps) -> Html {
+    html! {
+        <ManagerProvider manager={props.manager.clone()}>
+            <Comp />
+            <Comp />
+        </ManagerProvider>
+    }
+}
+
+#[tokio::test]
+async fn test_styled_context() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let manager = StyleManager::builder()
+        .prefix("ctx".into())
+        .class_name_generator(CounterClassNameGenerator::new())
+        .build()
+        .unwrap();
+
+    let body = LocalServerRenderer::<App>::with_props(AppProps {
+        manager: manager.clone(),
+    })
+    .hydratable(false)
+    .render()
+    .await;
+
+    assert_eq!(
+        body,
+        "<h1 class=\"ctx-0\">Hello!</h1><div class=\"ctx-1\">World!</div>\
+         <h1 class=\"ctx-0\">Hello!</h1><div class=\"ctx-1\">World!</div>"
+    );
+    assert_eq!(manager.style_count(), 2);
+}
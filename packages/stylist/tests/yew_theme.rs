@@ -0,0 +1,82 @@
+#![cfg(feature = "yew_integration")]
+
+use stylist::manager::{CounterClassNameGenerator, StyleManager};
+use stylist::yew::{use_theme, ManagerProvider, ThemeProvider};
+use stylist::CssVars;
+use yew::prelude::*;
+use yew::LocalServerRenderer;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Theme {
+    primary: String,
+}
+
+impl Theme {
+    fn new(primary: &str) -> Self {
+        Self {
+            primary: primary.to_string(),
+        }
+    }
+}
+
+impl CssVars for Theme {
+    fn css_vars(&self) -> Vec<(String, String)> {
+        vec![("--theme-primary".to_string(), self.primary.clone())]
+    }
+}
+
+#[function_component(Primary)]
+fn primary() -> Html {
+    let theme = use_theme::<Theme>();
+
+    html! {<span>{theme.primary.clone()}</span>}
+}
+
+#[derive(Properties, PartialEq)]
+struct AppProps {
+    manager: StyleManager,
+}
+
+#[function_component(App)]
+fn app(props: &AppProps) -> Html {
+    html! {
+        <ManagerProvider manager={props.manager.clone()}>
+            <ThemeProvider<Theme> theme={Theme::new("red")}>
+                <Primary />
+                <ThemeProvider<Theme> theme={Theme::new("blue")}>
+                    <Primary />
+                </ThemeProvider<Theme>>
+            </ThemeProvider<Theme>>
+        </ManagerProvider>
+    }
+}
+
+#[tokio::test]
+async fn test_theme_provider() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let manager = StyleManager::builder()
+        .prefix("theme".into())
+        .class_name_generator(CounterClassNameGenerator::new())
+        .build()
+        .unwrap();
+
+    let body = LocalServerRenderer::<App>::with_props(AppProps {
+        manager: manager.clone(),
+    })
+    .hydratable(false)
+    .render()
+    .await;
+
+    // The outermost theme is declared on `:root` and the nested theme on a wrapping element.
+    assert_eq!(
+        body,
+        "<span>red</span>\
+         <div style=\"display: contents;\" class=\"theme-1\"><span>blue</span></div>"
+    );
+    assert_eq!(
+        manager.render_style_tags(),
+        "<style data-style=\"theme-global-0\" data-style-rendered=\"\">:root {\n    --theme-primary: red;\n}\n</style>\
+         <style data-style=\"theme-1\" data-style-rendered=\"\">.theme-1 {\n    --theme-primary: blue;\n}\n</style>"
+    );
+}